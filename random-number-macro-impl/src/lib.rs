@@ -1,9 +1,14 @@
 use proc_macro::TokenStream;
+use proc_macro2::Span;
 use proc_macro_hack::proc_macro_hack;
 use quote::quote;
 use syn::{
+    bracketed,
     parse::{Parse, ParseStream},
-    parse_macro_input, Expr, RangeLimits, Token,
+    parse_macro_input,
+    punctuated::Punctuated,
+    token::Bracket,
+    Expr, Ident, RangeLimits, Token,
 };
 
 struct RandomBuilder {
@@ -12,6 +17,82 @@ struct RandomBuilder {
     rng:       Option<Box<Expr>>,
     exclusive: bool,
     cmp:       bool,
+    except:    Option<Vec<Expr>>,
+    weights:   Option<Vec<Expr>>,
+    p:         Option<Box<Expr>>,
+}
+
+fn peek_except(input: ParseStream) -> bool {
+    input.peek(Ident)
+        && input.peek2(Bracket)
+        && input.fork().parse::<Ident>().map(|ident| ident == "except").unwrap_or(false)
+}
+
+fn parse_except(input: ParseStream) -> Result<Vec<Expr>, syn::Error> {
+    input.parse::<Ident>()?;
+
+    parse_bracketed(input)
+}
+
+fn peek_weights(input: ParseStream) -> bool {
+    input.peek(Ident)
+        && input.peek2(Token!(=))
+        && input.fork().parse::<Ident>().map(|ident| ident == "weights").unwrap_or(false)
+}
+
+fn parse_weights(input: ParseStream) -> Result<Vec<Expr>, syn::Error> {
+    input.parse::<Ident>()?;
+    input.parse::<Token!(=)>()?;
+
+    parse_bracketed(input)
+}
+
+fn peek_bool(input: ParseStream) -> bool {
+    input.peek(Ident)
+        && input.peek2(Token!(,))
+        && input.fork().parse::<Ident>().map(|ident| ident == "bool").unwrap_or(false)
+}
+
+fn parse_p(input: ParseStream) -> Result<Expr, syn::Error> {
+    let ident = input.parse::<Ident>()?;
+
+    if ident != "p" {
+        return Err(syn::Error::new(ident.span(), "expected `p = <probability>`"));
+    }
+
+    input.parse::<Token!(=)>()?;
+
+    input.parse()
+}
+
+/// Turn `fuzz = u` into an expression which wraps `&mut u` in an `UnstructuredRng`. Other expressions are random number generators themselves.
+fn rng_expr(expr: Expr) -> Box<Expr> {
+    if let Expr::Assign(assign) = &expr {
+        if let Expr::Path(path) = assign.left.as_ref() {
+            if path.path.is_ident("fuzz") {
+                let u = &assign.right;
+
+                return Box::new(Expr::Verbatim(quote! {
+                    $crate::fuzz::UnstructuredRng::new(&mut #u)
+                }));
+            }
+        }
+    }
+
+    Box::new(expr)
+}
+
+fn parse_rng(input: ParseStream) -> Result<Box<Expr>, syn::Error> {
+    Ok(rng_expr(input.parse()?))
+}
+
+fn parse_bracketed(input: ParseStream) -> Result<Vec<Expr>, syn::Error> {
+    let content;
+    bracketed!(content in input);
+
+    let values = Punctuated::<Expr, Token!(,)>::parse_terminated(&content)?;
+
+    Ok(values.into_iter().collect())
 }
 
 impl Parse for RandomBuilder {
@@ -23,6 +104,33 @@ impl Parse for RandomBuilder {
                 rng:       None,
                 exclusive: false,
                 cmp:       false,
+                except:    None,
+                weights:   None,
+                p:         None,
+            })
+        } else if peek_bool(input) {
+            input.parse::<Ident>()?;
+            input.parse::<Token!(,)>()?;
+
+            let p = parse_p(input)?;
+
+            let mut rng = None;
+
+            if !input.is_empty() {
+                input.parse::<Token!(,)>()?;
+
+                rng = Some(parse_rng(input)?);
+            }
+
+            Ok(RandomBuilder {
+                min: None,
+                max: None,
+                rng,
+                exclusive: false,
+                cmp: false,
+                except: None,
+                weights: None,
+                p: Some(Box::new(p)),
             })
         } else {
             let expr: Expr = input.parse()?;
@@ -36,27 +144,40 @@ impl Parse for RandomBuilder {
                 let min = range.start;
                 let max = range.end;
 
-                if input.is_empty() {
-                    Ok(RandomBuilder {
-                        min,
-                        max,
-                        rng: None,
-                        exclusive,
-                        cmp: false,
-                    })
-                } else {
+                let mut rng = None;
+                let mut except = None;
+                let mut weights = None;
+
+                if !input.is_empty() {
                     input.parse::<Token!(,)>()?;
 
-                    let expr: Expr = input.parse()?;
+                    if peek_except(input) || peek_weights(input) {
+                        if peek_except(input) {
+                            except = Some(parse_except(input)?);
+                        } else {
+                            weights = Some(parse_weights(input)?);
+                        }
 
-                    Ok(RandomBuilder {
-                        min,
-                        max,
-                        rng: Some(Box::new(expr)),
-                        exclusive,
-                        cmp: false,
-                    })
+                        if !input.is_empty() {
+                            input.parse::<Token!(,)>()?;
+
+                            rng = Some(parse_rng(input)?);
+                        }
+                    } else {
+                        rng = Some(parse_rng(input)?);
+                    }
                 }
+
+                Ok(RandomBuilder {
+                    min,
+                    max,
+                    rng,
+                    exclusive,
+                    cmp: false,
+                    except,
+                    weights,
+                    p: None,
+                })
             } else if input.lookahead1().peek(Token!(,)) {
                 input.parse::<Token!(,)>()?;
 
@@ -69,6 +190,9 @@ impl Parse for RandomBuilder {
                         rng:       None,
                         exclusive: false,
                         cmp:       true,
+                        except:    None,
+                        weights:   None,
+                        p:         None,
                     })
                 } else {
                     input.parse::<Token!(,)>()?;
@@ -78,128 +202,251 @@ impl Parse for RandomBuilder {
                     Ok(RandomBuilder {
                         min:       Some(Box::from(expr)),
                         max:       Some(Box::from(expr2)),
-                        rng:       Some(Box::new(expr3)),
+                        rng:       Some(rng_expr(expr3)),
                         exclusive: false,
                         cmp:       true,
+                        except:    None,
+                        weights:   None,
+                        p:         None,
                     })
                 }
             } else {
                 Ok(RandomBuilder {
                     min:       None,
                     max:       None,
-                    rng:       Some(Box::new(expr)),
+                    rng:       Some(rng_expr(expr)),
                     exclusive: false,
                     cmp:       false,
+                    except:    None,
+                    weights:   None,
+                    p:         None,
                 })
             }
         }
     }
 }
 
+fn is_components(expr: &Expr) -> bool {
+    matches!(expr, Expr::Tuple(_) | Expr::Array(_) | Expr::Repeat(_))
+}
+
+fn is_literal(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(_) => true,
+        Expr::Unary(unary) => is_literal(&unary.expr),
+        Expr::Paren(paren) => is_literal(&paren.expr),
+        _ => false,
+    }
+}
+
+impl RandomBuilder {
+    /// Build an expression which evaluates `f(table, rng)` with the alias table of the weights. The table is cached in a thread-local variable if every weight is a literal.
+    fn with_weights(
+        &self,
+        weights: &[Expr],
+        f: impl FnOnce(proc_macro2::TokenStream, proc_macro2::TokenStream) -> proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let rng = match self.rng.as_ref() {
+            Some(rng) => quote! { &mut #rng },
+            None => quote! { &mut $crate::default_rng() },
+        };
+
+        let table = quote! {
+            $crate::AliasTable::new(&[#((#weights) as f64),*]).expect("random! called with invalid weights")
+        };
+
+        if weights.iter().all(is_literal) {
+            let body = f(quote! { table }, rng);
+
+            quote! {
+                {
+                    ::std::thread_local! {
+                        static TABLE: $crate::AliasTable = #table;
+                    }
+
+                    TABLE.with(|table| #body)
+                }
+            }
+        } else {
+            f(quote! { (&#table) }, rng)
+        }
+    }
+
+    /// Whether the bounds are tuples or arrays, whose components have their own ranges.
+    fn components(&self) -> bool {
+        !self.cmp
+            && (self.min.as_deref().map(is_components).unwrap_or(false)
+                || self.max.as_deref().map(is_components).unwrap_or(false))
+    }
+
+    fn range(&self) -> proc_macro2::TokenStream {
+        let min = self.min.as_ref().map(|min| quote! { (#min) });
+        let max = self.max.as_ref().map(|max| quote! { (#max) });
+
+        if self.exclusive || max.is_none() {
+            quote! { #min..#max }
+        } else {
+            quote! { #min..=#max }
+        }
+    }
+
+    /// Build the range like `range`, but in either order for two bounds separated by a comma.
+    fn ordered_range(&self) -> proc_macro2::TokenStream {
+        if self.cmp {
+            let min = &self.min;
+            let max = &self.max;
+
+            quote! {
+                {
+                    let (a, b) = (#min, #max);
+
+                    if b < a { b..=a } else { a..=b }
+                }
+            }
+        } else {
+            self.range()
+        }
+    }
+
+    /// Check that only a range is given, optionally followed by a generator if `rng` is `true`, for the macro `name`.
+    fn check_range_only(&self, name: &str, rng: bool) -> Result<(), syn::Error> {
+        let span = Span::call_site();
+
+        if self.p.is_some() || self.except.is_some() || self.weights.is_some() {
+            return Err(syn::Error::new(span, format!("{}! only takes a range", name)));
+        }
+
+        if self.components() {
+            return Err(syn::Error::new(
+                span,
+                format!("{}! does not take ranges of tuples or arrays", name),
+            ));
+        }
+
+        if rng != self.rng.is_some() {
+            let message = if rng {
+                format!("{}! takes a generator as the last argument", name)
+            } else {
+                format!("{}! does not take a random number generator", name)
+            };
+
+            return Err(syn::Error::new(span, message));
+        }
+
+        Ok(())
+    }
+}
+
 #[proc_macro_hack]
 pub fn random(input: TokenStream) -> TokenStream {
     let rb = parse_macro_input!(input as RandomBuilder);
 
-    let random = match rb.min.as_ref() {
-        Some(min) => match rb.max.as_ref() {
-            Some(max) => {
-                if rb.exclusive {
-                    match rb.rng.as_ref() {
-                        Some(rng) => {
-                            quote! {
-                                $crate::random_exclusively_with_rng(#min, #max, &mut #rng)
-                            }
-                        },
-                        None => {
-                            quote! {
-                                $crate::random_exclusively(#min, #max)
-                            }
-                        },
-                    }
-                } else if rb.cmp {
-                    match rb.rng.as_ref() {
-                        Some(rng) => {
-                            quote! {
-                                $crate::random_inclusively_cmp_with_rng(#min, #max, &mut #rng)
-                            }
-                        },
-                        None => {
-                            quote! {
-                                $crate::random_inclusively_cmp(#min, #max)
-                            }
-                        },
-                    }
-                } else {
-                    match rb.rng.as_ref() {
-                        Some(rng) => {
-                            quote! {
-                                $crate::random_inclusively_with_rng(#min, #max, &mut #rng)
-                            }
-                        },
-                        None => {
-                            quote! {
-                                $crate::random_inclusively(#min, #max)
-                            }
-                        },
-                    }
+    if let Some(p) = rb.p.as_ref() {
+        let random = match rb.rng.as_ref() {
+            Some(rng) => {
+                quote! {
+                    $crate::random_bool_with_rng(#p, &mut #rng)
                 }
             },
-            None => match rb.rng.as_ref() {
-                Some(rng) => {
-                    quote! {
-                        $crate::random_at_least_with_rng(#min, &mut #rng)
-                    }
-                },
-                None => {
-                    quote! {
-                        $crate::random_at_least(#min)
-                    }
-                },
+            None => {
+                quote! {
+                    $crate::random_bool(#p)
+                }
             },
-        },
-        None => match rb.max.as_ref() {
-            Some(max) => {
-                if rb.exclusive {
-                    match rb.rng.as_ref() {
-                        Some(rng) => {
-                            quote! {
-                                $crate::random_at_most_exclusively_with_rng(#max, &mut #rng)
-                            }
-                        },
-                        None => {
-                            quote! {
-                                $crate::random_at_most_exclusively(#max)
-                            }
-                        },
-                    }
-                } else {
-                    match rb.rng.as_ref() {
-                        Some(rng) => {
-                            quote! {
-                                $crate::random_at_most_with_rng(#max, &mut #rng)
-                            }
-                        },
-                        None => {
-                            quote! {
-                                $crate::random_at_most(#max)
-                            }
-                        },
-                    }
+        };
+
+        return random.into();
+    }
+
+    if let Some(except) = rb.except.as_ref() {
+        let range = rb.range();
+
+        let rng = match rb.rng.as_ref() {
+            Some(rng) => quote! { &mut #rng },
+            None => quote! { &mut $crate::default_rng() },
+        };
+
+        // the excluded values and ranges may have different types, so each one is converted on its own
+        let random = quote! {
+            {
+                let mut __random_number_excluded = $crate::MacroExclusions::new();
+
+                #(__random_number_excluded.push(#except);)*
+
+                __random_number_excluded.sample_with_rng(#range, #rng)
+            }
+        };
+
+        return random.into();
+    }
+
+    if let Some(weights) = rb.weights.as_ref() {
+        let range = rb.range();
+
+        let random = rb.with_weights(weights, |table, rng| {
+            quote! {
+                #table.sample_ranged_with_rng(#range, #rng)
+            }
+        });
+
+        return random.into();
+    }
+
+    if rb.components() {
+        let range = rb.range();
+
+        let random = match rb.rng.as_ref() {
+            Some(rng) => {
+                quote! {
+                    $crate::random_components_ranged_with_rng(#range, &mut #rng)
                 }
             },
-            None => match rb.rng.as_ref() {
-                Some(rng) => {
-                    quote! {
-                        $crate::random_with_rng(&mut #rng)
-                    }
-                },
-                None => {
-                    quote! {
-                        $crate::random()
-                    }
-                },
+            None => {
+                quote! {
+                    $crate::random_components_ranged(#range)
+                }
             },
-        },
+        };
+
+        return random.into();
+    }
+
+    if rb.min.is_none() && rb.max.is_none() {
+        let random = match rb.rng.as_ref() {
+            Some(rng) => {
+                quote! {
+                    $crate::random_with_rng(&mut #rng)
+                }
+            },
+            None => {
+                quote! {
+                    $crate::random()
+                }
+            },
+        };
+
+        return random.into();
+    }
+
+    let range = rb.ordered_range();
+
+    let rng = match rb.rng.as_ref() {
+        Some(rng) => quote! { &mut #rng },
+        None => quote! { &mut $crate::default_rng() },
+    };
+
+    // pick the function by the type of the bounds, because IP addresses can not implement `SampleUniform`
+    let random = quote! {
+        {
+            #[allow(unused_imports)]
+            use $crate::{MacroIpv4Dispatch as _, MacroIpv6Dispatch as _, MacroUniformDispatch as _};
+
+            let __random_number_range = #range;
+
+            (&$crate::macro_range_bound(&__random_number_range))
+                .__random_number_sampler()
+                .sample_with_rng(__random_number_range, #rng)
+        }
     };
 
     random.into()
@@ -239,6 +486,75 @@ pub fn random_fill(input: TokenStream) -> TokenStream {
 
     let rb = rfb.rb;
 
+    if let Some(p) = rb.p.as_ref() {
+        let random_fill = match rb.rng.as_ref() {
+            Some(rng) => {
+                quote! {
+                    $crate::random_fill_bool_with_rng(#out.as_mut(), #p, &mut #rng)
+                }
+            },
+            None => {
+                quote! {
+                    $crate::random_fill_bool(#out.as_mut(), #p)
+                }
+            },
+        };
+
+        return random_fill.into();
+    }
+
+    if let Some(except) = rb.except.as_ref() {
+        let range = rb.range();
+
+        let rng = match rb.rng.as_ref() {
+            Some(rng) => quote! { &mut #rng },
+            None => quote! { &mut $crate::default_rng() },
+        };
+
+        let random_fill = quote! {
+            {
+                let mut __random_number_excluded = $crate::MacroExclusions::new();
+
+                #(__random_number_excluded.push(#except);)*
+
+                __random_number_excluded.fill_with_rng(#out.as_mut(), #range, #rng)
+            }
+        };
+
+        return random_fill.into();
+    }
+
+    if let Some(weights) = rb.weights.as_ref() {
+        let range = rb.range();
+
+        let random_fill = rb.with_weights(weights, |table, rng| {
+            quote! {
+                #table.fill_ranged_with_rng(#out.as_mut(), #range, #rng)
+            }
+        });
+
+        return random_fill.into();
+    }
+
+    if rb.components() {
+        let range = rb.range();
+
+        let random_fill = match rb.rng.as_ref() {
+            Some(rng) => {
+                quote! {
+                    $crate::random_fill_components_ranged_with_rng(#out.as_mut(), #range, &mut #rng)
+                }
+            },
+            None => {
+                quote! {
+                    $crate::random_fill_components_ranged(#out.as_mut(), #range)
+                }
+            },
+        };
+
+        return random_fill.into();
+    }
+
     let random_fill = match rb.min.as_ref() {
         Some(min) => match rb.max.as_ref() {
             Some(max) => {
@@ -343,3 +659,38 @@ pub fn random_fill(input: TokenStream) -> TokenStream {
 
     random_fill.into()
 }
+
+#[proc_macro_hack]
+pub fn strategy(input: TokenStream) -> TokenStream {
+    let rb = parse_macro_input!(input as RandomBuilder);
+
+    if let Err(error) = rb.check_range_only("strategy", false) {
+        return error.to_compile_error().into();
+    }
+
+    let range = rb.ordered_range();
+
+    let strategy = quote! {
+        $crate::proptest::RangeStrategy::new(#range)
+    };
+
+    strategy.into()
+}
+
+#[proc_macro_hack]
+pub fn arbitrary(input: TokenStream) -> TokenStream {
+    let rb = parse_macro_input!(input as RandomBuilder);
+
+    if let Err(error) = rb.check_range_only("arbitrary", true) {
+        return error.to_compile_error().into();
+    }
+
+    let range = rb.ordered_range();
+    let g = rb.rng.as_ref().unwrap();
+
+    let arbitrary = quote! {
+        $crate::quickcheck::arbitrary_ranged(#range, #g)
+    };
+
+    arbitrary.into()
+}
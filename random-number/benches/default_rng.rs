@@ -0,0 +1,69 @@
+use bencher::{benchmark_group, benchmark_main, black_box, Bencher};
+use rand_chacha::ChaCha8Rng;
+use rand_pcg::Pcg64Mcg;
+use rand_xoshiro::Xoshiro256PlusPlus;
+use random_number::rand::{thread_rng, SeedableRng};
+use wyhash::WyRng;
+
+macro_rules! bench_rng {
+    ($random:ident, $random_ranged:ident, $random_fill:ident, $rng:expr) => {
+        fn $random(bencher: &mut Bencher) {
+            let mut rng = $rng;
+
+            bencher.iter(|| random_number::random_with_rng::<u64, _>(&mut rng));
+        }
+
+        fn $random_ranged(bencher: &mut Bencher) {
+            let mut rng = $rng;
+
+            bencher.iter(|| random_number::random_ranged_with_rng::<u32, _, _>(1..=6, &mut rng));
+        }
+
+        fn $random_fill(bencher: &mut Bencher) {
+            let mut rng = $rng;
+            let mut out = [0u64; 1024];
+
+            bencher.iter(|| random_number::random_fill_with_rng(black_box(&mut out), &mut rng));
+        }
+    };
+}
+
+bench_rng!(
+    default_rng_random,
+    default_rng_random_ranged,
+    default_rng_random_fill,
+    random_number::default_rng()
+);
+bench_rng!(thread_rng_random, thread_rng_random_ranged, thread_rng_random_fill, thread_rng());
+bench_rng!(chacha8_random, chacha8_random_ranged, chacha8_random_fill, ChaCha8Rng::from_entropy());
+bench_rng!(pcg64mcg_random, pcg64mcg_random_ranged, pcg64mcg_random_fill, Pcg64Mcg::from_entropy());
+bench_rng!(
+    xoshiro256plusplus_random,
+    xoshiro256plusplus_random_ranged,
+    xoshiro256plusplus_random_fill,
+    Xoshiro256PlusPlus::from_entropy()
+);
+bench_rng!(wyrand_random, wyrand_random_ranged, wyrand_random_fill, WyRng::from_entropy());
+
+benchmark_group!(
+    default_rng,
+    default_rng_random,
+    default_rng_random_ranged,
+    default_rng_random_fill,
+    thread_rng_random,
+    thread_rng_random_ranged,
+    thread_rng_random_fill,
+    chacha8_random,
+    chacha8_random_ranged,
+    chacha8_random_fill,
+    pcg64mcg_random,
+    pcg64mcg_random_ranged,
+    pcg64mcg_random_fill,
+    xoshiro256plusplus_random,
+    xoshiro256plusplus_random_ranged,
+    xoshiro256plusplus_random_fill,
+    wyrand_random,
+    wyrand_random_ranged,
+    wyrand_random_fill
+);
+benchmark_main!(default_rng);
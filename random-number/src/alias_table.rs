@@ -0,0 +1,172 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    ops::RangeBounds,
+};
+
+use crate::{rand::Rng, random_except_functions::index_bounds, Discrete};
+
+/// Errors of building a weighted distribution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WeightError {
+    /// There is no weight.
+    NoItem,
+    /// A weight is negative, infinite or NaN, or the weights sum up to infinity.
+    InvalidWeight,
+    /// Every weight is zero.
+    AllWeightsZero,
+    /// There is no item with the id.
+    UnknownId,
+}
+
+impl Display for WeightError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            WeightError::NoItem => f.write_str("there is no weight"),
+            WeightError::InvalidWeight => f.write_str(
+                "a weight is negative, infinite or NaN, or the weights sum up to infinity",
+            ),
+            WeightError::AllWeightsZero => f.write_str("every weight is zero"),
+            WeightError::UnknownId => f.write_str("there is no item with the id"),
+        }
+    }
+}
+
+impl Error for WeightError {}
+
+/// A table for sampling indices with given weights in O(1) time, built by Vose's alias method.
+#[derive(Debug, Clone)]
+pub struct AliasTable {
+    probabilities: Vec<f64>,
+    aliases:       Vec<usize>,
+}
+
+impl AliasTable {
+    /// Build a table from the weights of the indices [0, `weights.len()`).
+    pub fn new(weights: &[f64]) -> Result<Self, WeightError> {
+        let n = weights.len();
+
+        if n == 0 {
+            return Err(WeightError::NoItem);
+        }
+
+        let mut sum = 0f64;
+
+        for &weight in weights {
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(WeightError::InvalidWeight);
+            }
+
+            sum += weight;
+        }
+
+        if sum <= 0.0 {
+            return Err(WeightError::AllWeightsZero);
+        }
+
+        if !sum.is_finite() {
+            return Err(WeightError::InvalidWeight);
+        }
+
+        let mut scaled: Vec<f64> = weights.iter().map(|&weight| weight * n as f64 / sum).collect();
+        let mut probabilities = vec![1f64; n];
+        let mut aliases: Vec<usize> = (0..n).collect();
+
+        let mut small = Vec::with_capacity(n);
+        let mut large = Vec::with_capacity(n);
+
+        for (i, &p) in scaled.iter().enumerate() {
+            if p < 1.0 {
+                small.push(i);
+            } else {
+                large.push(i);
+            }
+        }
+
+        while let (Some(&l), Some(&g)) = (small.last(), large.last()) {
+            small.pop();
+            large.pop();
+
+            probabilities[l] = scaled[l];
+            aliases[l] = g;
+
+            scaled[g] = (scaled[g] + scaled[l]) - 1.0;
+
+            if scaled[g] < 1.0 {
+                small.push(g);
+            } else {
+                large.push(g);
+            }
+        }
+
+        // whatever is left over is only off from 1 by rounding errors
+
+        Ok(AliasTable {
+            probabilities,
+            aliases,
+        })
+    }
+
+    /// The number of indices.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.probabilities.len()
+    }
+
+    /// Always `false`, because a table has at least one index.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.probabilities.is_empty()
+    }
+
+    /// Sample an index in the range [0, `self.len()`) with an existing random number generator.
+    #[inline]
+    pub fn sample_with_rng<T: Rng>(&self, rng: &mut T) -> usize {
+        let i = rng.gen_range(0..self.probabilities.len());
+
+        if rng.gen::<f64>() < self.probabilities[i] {
+            i
+        } else {
+            self.aliases[i]
+        }
+    }
+
+    /// Sample a value in a specific range, where the `i`-th value of the range has the `i`-th weight, with an existing random number generator.
+    ///
+    /// Panics if the start bound is exclusive or the range does not contain exactly `self.len()` values.
+    #[inline]
+    pub fn sample_ranged_with_rng<X: Discrete, R: RangeBounds<X>, T: Rng>(
+        &self,
+        range: R,
+        rng: &mut T,
+    ) -> X {
+        let start = self.range_start(&range, "sample_ranged_with_rng");
+
+        X::from_index(start + self.sample_with_rng(rng) as u128)
+    }
+
+    /// Fill a slice with values in a specific range, where the `i`-th value of the range has the `i`-th weight, with an existing random number generator.
+    ///
+    /// Panics if the start bound is exclusive or the range does not contain exactly `self.len()` values.
+    #[inline]
+    pub fn fill_ranged_with_rng<X: Discrete, R: RangeBounds<X>, T: Rng>(
+        &self,
+        out: &mut [X],
+        range: R,
+        rng: &mut T,
+    ) {
+        let start = self.range_start(&range, "fill_ranged_with_rng");
+
+        for x in out.iter_mut() {
+            *x = X::from_index(start + self.sample_with_rng(rng) as u128);
+        }
+    }
+
+    fn range_start<X: Discrete, R: RangeBounds<X>>(&self, range: &R, caller: &str) -> u128 {
+        match index_bounds(range, caller) {
+            Some((start, end)) if end - start == self.len() as u128 - 1 => start,
+            _ => panic!("{} called with a range which does not have {} values", caller, self.len()),
+        }
+    }
+}
@@ -1,7 +1,31 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::rand::{
+    distributions::{
+        uniform::{SampleUniform, Uniform},
+        Distribution,
+    },
+    Rng,
+};
+
 /// The random range of different types.
 pub trait Bounded {
     fn max_value() -> Self;
     fn min_value() -> Self;
+
+    /// Fill a slice with random values in the range [`Self::min_value()`, `Self::max_value()`].
+    ///
+    /// Types whose full range maps onto raw random bytes override this to delegate to `RngCore::fill_bytes`.
+    #[inline]
+    fn fill_full_range<T: Rng>(out: &mut [Self], rng: &mut T)
+    where
+        Self: SampleUniform + Sized, {
+        let uniform = Uniform::new_inclusive(Self::min_value(), Self::max_value());
+
+        for x in out.iter_mut() {
+            *x = uniform.sample(rng);
+        }
+    }
 }
 
 macro_rules! bounded_impl {
@@ -20,13 +44,34 @@ macro_rules! bounded_impl {
     };
 }
 
-bounded_impl!(u8);
+macro_rules! bounded_bytes_impl {
+    ($t:ident) => {
+        impl Bounded for $t {
+            #[inline]
+            fn max_value() -> Self {
+                $t::MAX
+            }
+
+            #[inline]
+            fn min_value() -> Self {
+                $t::MIN
+            }
+
+            #[inline]
+            fn fill_full_range<T: Rng>(out: &mut [Self], rng: &mut T) {
+                rng.fill(out);
+            }
+        }
+    };
+}
+
+bounded_bytes_impl!(u8);
 bounded_impl!(u16);
 bounded_impl!(u32);
 bounded_impl!(u64);
 bounded_impl!(u128);
 bounded_impl!(usize);
-bounded_impl!(i8);
+bounded_bytes_impl!(i8);
 bounded_impl!(i16);
 bounded_impl!(i32);
 bounded_impl!(i64);
@@ -56,3 +101,68 @@ impl Bounded for f32 {
         0.0
     }
 }
+
+impl Bounded for Ipv4Addr {
+    #[inline]
+    fn max_value() -> Self {
+        Ipv4Addr::BROADCAST
+    }
+
+    #[inline]
+    fn min_value() -> Self {
+        Ipv4Addr::UNSPECIFIED
+    }
+}
+
+impl Bounded for Ipv6Addr {
+    #[inline]
+    fn max_value() -> Self {
+        Ipv6Addr::from(u128::MAX)
+    }
+
+    #[inline]
+    fn min_value() -> Self {
+        Ipv6Addr::UNSPECIFIED
+    }
+}
+
+macro_rules! bounded_tuple_impl {
+    ($($x:ident),+) => {
+        impl<$($x: Bounded),+> Bounded for ($($x,)+) {
+            #[inline]
+            fn max_value() -> Self {
+                ($($x::max_value(),)+)
+            }
+
+            #[inline]
+            fn min_value() -> Self {
+                ($($x::min_value(),)+)
+            }
+        }
+    };
+}
+
+bounded_tuple_impl!(A);
+bounded_tuple_impl!(A, B);
+bounded_tuple_impl!(A, B, C);
+bounded_tuple_impl!(A, B, C, D);
+bounded_tuple_impl!(A, B, C, D, E);
+bounded_tuple_impl!(A, B, C, D, E, F);
+bounded_tuple_impl!(A, B, C, D, E, F, G);
+bounded_tuple_impl!(A, B, C, D, E, F, G, H);
+bounded_tuple_impl!(A, B, C, D, E, F, G, H, I);
+bounded_tuple_impl!(A, B, C, D, E, F, G, H, I, J);
+bounded_tuple_impl!(A, B, C, D, E, F, G, H, I, J, K);
+bounded_tuple_impl!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+impl<X: Bounded, const N: usize> Bounded for [X; N] {
+    #[inline]
+    fn max_value() -> Self {
+        [(); N].map(|_| X::max_value())
+    }
+
+    #[inline]
+    fn min_value() -> Self {
+        [(); N].map(|_| X::min_value())
+    }
+}
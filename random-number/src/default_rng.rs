@@ -0,0 +1,97 @@
+use std::{cell::UnsafeCell, rc::Rc};
+
+use crate::rand::{CryptoRng, Error, RngCore};
+
+/// A handle to a lazily-initialized thread-local random number generator of type `R`, seeded by the operating system, just like `rand::rngs::ThreadRng` but for other generators.
+#[derive(Debug, Clone)]
+pub struct ThreadLocalRng<R> {
+    // `Rc` makes the handle `!Send`, so it never leaves its thread
+    rng: Rc<UnsafeCell<R>>,
+}
+
+impl<R: RngCore> RngCore for ThreadLocalRng<R> {
+    // SAFETY: the generator is only reachable through handles of the current thread, and none of these methods can call back into another handle while the mutable reference is alive
+
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        unsafe { &mut *self.rng.get() }.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        unsafe { &mut *self.rng.get() }.next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        unsafe { &mut *self.rng.get() }.fill_bytes(dest)
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        unsafe { &mut *self.rng.get() }.try_fill_bytes(dest)
+    }
+}
+
+impl<R: RngCore + CryptoRng> CryptoRng for ThreadLocalRng<R> {}
+
+#[cfg(any(
+    feature = "rng-chacha",
+    feature = "rng-pcg",
+    feature = "rng-xoshiro",
+    feature = "rng-wyrand"
+))]
+macro_rules! thread_local_rng_impl {
+    ($t:ty) => {
+        /// The random number generator used by the functions and macros without an explicit random number generator.
+        pub type DefaultRng = ThreadLocalRng<$t>;
+
+        thread_local! {
+            static DEFAULT_RNG: Rc<UnsafeCell<$t>> = Rc::new(UnsafeCell::new(<$t as crate::rand::SeedableRng>::from_entropy()));
+        }
+
+        /// Get a handle to the lazily-initialized thread-local random number generator used by the functions and macros without an explicit random number generator.
+        #[inline]
+        pub fn default_rng() -> DefaultRng {
+            ThreadLocalRng {
+                rng: DEFAULT_RNG.with(Rc::clone)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "rng-chacha")]
+thread_local_rng_impl!(rand_chacha::ChaCha8Rng);
+
+#[cfg(all(feature = "rng-pcg", not(feature = "rng-chacha")))]
+thread_local_rng_impl!(rand_pcg::Pcg64Mcg);
+
+#[cfg(all(feature = "rng-xoshiro", not(any(feature = "rng-chacha", feature = "rng-pcg"))))]
+thread_local_rng_impl!(rand_xoshiro::Xoshiro256PlusPlus);
+
+#[cfg(all(
+    feature = "rng-wyrand",
+    not(any(feature = "rng-chacha", feature = "rng-pcg", feature = "rng-xoshiro"))
+))]
+thread_local_rng_impl!(wyhash::WyRng);
+
+/// The random number generator used by the functions and macros without an explicit random number generator.
+#[cfg(not(any(
+    feature = "rng-chacha",
+    feature = "rng-pcg",
+    feature = "rng-xoshiro",
+    feature = "rng-wyrand"
+)))]
+pub type DefaultRng = crate::rand::rngs::ThreadRng;
+
+/// Get a handle to the lazily-initialized thread-local random number generator used by the functions and macros without an explicit random number generator.
+#[cfg(not(any(
+    feature = "rng-chacha",
+    feature = "rng-pcg",
+    feature = "rng-xoshiro",
+    feature = "rng-wyrand"
+)))]
+#[inline]
+pub fn default_rng() -> DefaultRng {
+    crate::rand::thread_rng()
+}
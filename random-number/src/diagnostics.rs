@@ -0,0 +1,357 @@
+/*!
+Statistical tests for checking that generated values follow the distribution they should, such as the chi-square test, the Kolmogorov-Smirnov test and the runs test.
+
+Each test returns a `TestResult` with a p-value, the probability of seeing a deviation at least as large if the values really follow the distribution. A tiny p-value means the values are biased or correlated.
+
+```rust
+use random_number::diagnostics;
+
+let mut rng = random_number::rand::thread_rng();
+
+let mut counts = [0u64; 6];
+
+for _ in 0..6000 {
+    let n: usize = random_number::random!(0..6, rng);
+
+    counts[n] += 1;
+}
+
+let result = diagnostics::chi_square_uniform_test(&counts);
+
+println!("{:?}", result);
+assert!(result.passes(1e-6));
+```
+*/
+
+use std::f64::consts::PI;
+
+/// The outcome of a statistical test.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TestResult {
+    /// The test statistic.
+    pub statistic: f64,
+    /// The probability of a statistic at least as extreme under the null hypothesis.
+    pub p_value:   f64,
+}
+
+impl TestResult {
+    /// Whether the null hypothesis is kept at the significance level `alpha`, that is, the p-value is not less than `alpha`.
+    #[inline]
+    pub fn passes(&self, alpha: f64) -> bool {
+        self.p_value >= alpha
+    }
+}
+
+/// The natural logarithm of the gamma function, by the Lanczos approximation.
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+
+    if x < 0.5 {
+        // the reflection formula
+        (PI / (PI * x).sin()).ln() - ln_gamma(1.0 - x)
+    } else {
+        let x = x - 1.0;
+        let t = x + 7.5;
+
+        let mut a = COEFFICIENTS[0];
+
+        for (i, c) in COEFFICIENTS.iter().enumerate().skip(1) {
+            a += c / (x + i as f64);
+        }
+
+        0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + a.ln()
+    }
+}
+
+/// The regularized upper incomplete gamma function Q(`a`, `x`).
+fn gamma_q(a: f64, x: f64) -> f64 {
+    const EPSILON: f64 = 1e-15;
+    const TINY: f64 = 1e-300;
+
+    if x <= 0.0 {
+        return 1.0;
+    }
+
+    let prefactor = (-x + a * x.ln() - ln_gamma(a)).exp();
+
+    if x < a + 1.0 {
+        // the series of P(a, x)
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        let mut n = a;
+
+        for _ in 0..10000 {
+            n += 1.0;
+            term *= x / n;
+            sum += term;
+
+            if term.abs() < sum.abs() * EPSILON {
+                break;
+            }
+        }
+
+        (1.0 - sum * prefactor).max(0.0)
+    } else {
+        // the continued fraction of Q(a, x) by the modified Lentz's method
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / TINY;
+        let mut d = 1.0 / b;
+        let mut h = d;
+
+        for i in 1..10000 {
+            let an = -(i as f64) * (i as f64 - a);
+
+            b += 2.0;
+
+            d = an * d + b;
+
+            if d.abs() < TINY {
+                d = TINY;
+            }
+
+            c = b + an / c;
+
+            if c.abs() < TINY {
+                c = TINY;
+            }
+
+            d = 1.0 / d;
+
+            let delta = d * c;
+
+            h *= delta;
+
+            if (delta - 1.0).abs() < EPSILON {
+                break;
+            }
+        }
+
+        (h * prefactor).min(1.0)
+    }
+}
+
+/// The two-sided p-value of a standard normal statistic.
+#[inline]
+fn normal_two_sided_p_value(z: f64) -> f64 {
+    // erfc(|z| / √2) = Q(1/2, z² / 2)
+    gamma_q(0.5, z * z / 2.0)
+}
+
+/// The complementary cumulative distribution function of the Kolmogorov distribution.
+fn kolmogorov_q(lambda: f64) -> f64 {
+    if lambda < 0.2 {
+        return 1.0;
+    }
+
+    let mut sum = 0.0;
+    let mut sign = 2.0;
+
+    for k in 1..=100 {
+        let term = sign * (-2.0 * (k * k) as f64 * lambda * lambda).exp();
+
+        sum += term;
+
+        if term.abs() < 1e-16 {
+            break;
+        }
+
+        sign = -sign;
+    }
+
+    sum.clamp(0.0, 1.0)
+}
+
+/// Run Pearson's chi-square goodness-of-fit test on the `observed` counts of categories against their `expected` counts, with `observed.len() - 1` degrees of freedom.
+///
+/// Panics if the lengths of `observed` and `expected` differ, there are fewer than two categories, or an expected count is not positive.
+///
+/// ## Examples
+///
+/// ```rust
+/// use random_number::diagnostics;
+///
+/// let result =
+///     diagnostics::chi_square_test(&[10, 20, 70], &[10.0, 20.0, 70.0]);
+///
+/// assert_eq!(0.0, result.statistic);
+/// assert_eq!(1.0, result.p_value);
+///
+/// let result = diagnostics::chi_square_test(&[50, 50], &[90.0, 10.0]);
+///
+/// assert!(!result.passes(1e-6));
+/// ```
+pub fn chi_square_test(observed: &[u64], expected: &[f64]) -> TestResult {
+    if observed.len() != expected.len() {
+        panic!("chi_square_test called with observed and expected counts of different lengths");
+    }
+
+    if observed.len() < 2 {
+        panic!("chi_square_test called with fewer than two categories");
+    }
+
+    let mut statistic = 0.0;
+
+    for (&o, &e) in observed.iter().zip(expected.iter()) {
+        if e.is_nan() || e <= 0.0 {
+            panic!("chi_square_test called with an expected count which is not positive");
+        }
+
+        let d = o as f64 - e;
+
+        statistic += d * d / e;
+    }
+
+    let degrees_of_freedom = (observed.len() - 1) as f64;
+
+    TestResult {
+        statistic,
+        p_value: gamma_q(degrees_of_freedom / 2.0, statistic / 2.0),
+    }
+}
+
+/// Run Pearson's chi-square goodness-of-fit test on the `observed` counts of categories which should be equally likely.
+///
+/// Panics if there are fewer than two categories or no observations.
+#[inline]
+pub fn chi_square_uniform_test(observed: &[u64]) -> TestResult {
+    let total: u64 = observed.iter().sum();
+
+    let expected = vec![total as f64 / observed.len() as f64; observed.len()];
+
+    chi_square_test(observed, &expected)
+}
+
+/// Run the one-sample Kolmogorov-Smirnov test on `samples` against a continuous cumulative distribution function `cdf`. The statistic is the largest distance between the empirical and the given distribution functions, and the p-value is from Stephens' approximation of its asymptotic distribution.
+///
+/// Panics if `samples` is empty or contains NaN.
+///
+/// ## Examples
+///
+/// ```rust
+/// use random_number::diagnostics;
+///
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// let mut samples = vec![0f64; 1000];
+/// random_number::random_fill_ranged_with_rng(&mut samples, 0.0..1.0, &mut rng);
+///
+/// assert!(diagnostics::kolmogorov_smirnov_test(&samples, |x| x).passes(1e-6));
+///
+/// // squared values are not uniform
+/// let squared: Vec<f64> = samples.iter().map(|x| x * x).collect();
+///
+/// assert!(!diagnostics::kolmogorov_smirnov_test(&squared, |x| x).passes(1e-6));
+/// ```
+pub fn kolmogorov_smirnov_test<F: Fn(f64) -> f64>(samples: &[f64], cdf: F) -> TestResult {
+    if samples.is_empty() {
+        panic!("kolmogorov_smirnov_test called with no samples");
+    }
+
+    let mut sorted = samples.to_vec();
+
+    sorted.sort_by(|a, b| {
+        a.partial_cmp(b).expect("kolmogorov_smirnov_test called with a sample which is NaN")
+    });
+
+    let n = sorted.len() as f64;
+
+    let mut statistic = 0.0f64;
+
+    for (i, &x) in sorted.iter().enumerate() {
+        let f = cdf(x);
+
+        statistic = statistic.max(f - i as f64 / n).max((i + 1) as f64 / n - f);
+    }
+
+    let sqrt_n = n.sqrt();
+
+    TestResult {
+        statistic,
+        p_value: kolmogorov_q((sqrt_n + 0.12 + 0.11 / sqrt_n) * statistic),
+    }
+}
+
+/// Run the Wald-Wolfowitz runs test on `samples` for serial correlation. The samples are classified as above or below their median, those equal to the median are dropped, and the number of runs of the same class is compared with its distribution for independent samples. The statistic is the standard score of the number of runs.
+///
+/// Panics if `samples` contains NaN. The p-value is 1 if too few samples are left to have runs of both classes.
+///
+/// ## Examples
+///
+/// ```rust
+/// use random_number::diagnostics;
+///
+/// // alternating values are far from independent
+/// let samples: Vec<f64> = (0..1000).map(|i| (i % 2) as f64).collect();
+///
+/// assert!(!diagnostics::runs_test(&samples).passes(1e-6));
+/// ```
+pub fn runs_test(samples: &[f64]) -> TestResult {
+    let mut sorted = samples.to_vec();
+
+    sorted.sort_by(|a, b| a.partial_cmp(b).expect("runs_test called with a sample which is NaN"));
+
+    let median = match sorted.len() {
+        0 => 0.0,
+        n if n % 2 == 1 => sorted[n / 2],
+        n => (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0,
+    };
+
+    let mut above = 0u64;
+    let mut below = 0u64;
+    let mut runs = 0u64;
+    let mut previous = None;
+
+    for &x in samples {
+        let class = if x > median {
+            above += 1;
+
+            true
+        } else if x < median {
+            below += 1;
+
+            false
+        } else {
+            continue;
+        };
+
+        if previous != Some(class) {
+            runs += 1;
+        }
+
+        previous = Some(class);
+    }
+
+    let no_runs = TestResult {
+        statistic: 0.0, p_value: 1.0
+    };
+
+    if above == 0 || below == 0 {
+        return no_runs;
+    }
+
+    let (n1, n2) = (above as f64, below as f64);
+
+    let mean = 2.0 * n1 * n2 / (n1 + n2) + 1.0;
+    let variance = (mean - 1.0) * (mean - 2.0) / (n1 + n2 - 1.0);
+
+    if variance <= 0.0 {
+        return no_runs;
+    }
+
+    let statistic = (runs as f64 - mean) / variance.sqrt();
+
+    TestResult {
+        statistic,
+        p_value: normal_two_sided_p_value(statistic),
+    }
+}
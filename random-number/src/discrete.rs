@@ -0,0 +1,78 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::Bounded;
+
+/// Types whose values can be enumerated in order, mapping [`Bounded::min_value()`, `Bounded::max_value()`] onto the indices [0, `Bounded::max_value().to_index()`].
+pub trait Discrete: Bounded + Copy + Ord {
+    fn to_index(self) -> u128;
+    fn from_index(index: u128) -> Self;
+}
+
+macro_rules! discrete_unsigned_impl {
+    ($t:ident) => {
+        impl Discrete for $t {
+            #[inline]
+            fn to_index(self) -> u128 {
+                self as u128
+            }
+
+            #[inline]
+            fn from_index(index: u128) -> Self {
+                index as $t
+            }
+        }
+    };
+}
+
+macro_rules! discrete_signed_impl {
+    ($t:ident, $u:ident) => {
+        impl Discrete for $t {
+            #[inline]
+            fn to_index(self) -> u128 {
+                (self as $u ^ ($t::MIN as $u)) as u128
+            }
+
+            #[inline]
+            fn from_index(index: u128) -> Self {
+                (index as $u ^ ($t::MIN as $u)) as $t
+            }
+        }
+    };
+}
+
+discrete_unsigned_impl!(u8);
+discrete_unsigned_impl!(u16);
+discrete_unsigned_impl!(u32);
+discrete_unsigned_impl!(u64);
+discrete_unsigned_impl!(u128);
+discrete_unsigned_impl!(usize);
+discrete_signed_impl!(i8, u8);
+discrete_signed_impl!(i16, u16);
+discrete_signed_impl!(i32, u32);
+discrete_signed_impl!(i64, u64);
+discrete_signed_impl!(i128, u128);
+discrete_signed_impl!(isize, usize);
+
+impl Discrete for Ipv4Addr {
+    #[inline]
+    fn to_index(self) -> u128 {
+        u32::from(self) as u128
+    }
+
+    #[inline]
+    fn from_index(index: u128) -> Self {
+        Ipv4Addr::from(index as u32)
+    }
+}
+
+impl Discrete for Ipv6Addr {
+    #[inline]
+    fn to_index(self) -> u128 {
+        u128::from(self)
+    }
+
+    #[inline]
+    fn from_index(index: u128) -> Self {
+        Ipv6Addr::from(index)
+    }
+}
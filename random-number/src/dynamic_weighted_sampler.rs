@@ -0,0 +1,267 @@
+use crate::{default_rng, rand::Rng, WeightError};
+
+/// A collection of items with weights which can be changed at any time, for sampling an item in O(log n) time.
+///
+/// The weights are kept in a sum tree. Every internal node is recomputed from its children when a weight changes, so rounding errors do not pile up over many updates.
+#[derive(Debug, Clone)]
+pub struct DynamicWeightedSampler<T> {
+    slots:    Vec<Option<T>>,
+    /// `tree[1]` is the root, and the leaf of the slot `i` is `tree[capacity + i]`.
+    tree:     Vec<f64>,
+    capacity: usize,
+    free:     Vec<usize>,
+    len:      usize,
+}
+
+impl<T> Default for DynamicWeightedSampler<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[inline]
+fn check_weight(weight: f64) -> Result<(), WeightError> {
+    if weight.is_finite() && weight >= 0.0 {
+        Ok(())
+    } else {
+        Err(WeightError::InvalidWeight)
+    }
+}
+
+impl<T> DynamicWeightedSampler<T> {
+    /// Create an empty sampler.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Create an empty sampler with space for at least `capacity` items.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let capacity = capacity.next_power_of_two();
+
+        DynamicWeightedSampler {
+            slots: Vec::with_capacity(capacity),
+            tree: vec![0f64; capacity * 2],
+            capacity,
+            free: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// The number of items.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether there is no item.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The sum of all weights.
+    #[inline]
+    pub fn total_weight(&self) -> f64 {
+        self.tree[1]
+    }
+
+    /// Get the item with a specific id.
+    #[inline]
+    pub fn get(&self, id: usize) -> Option<&T> {
+        self.slots.get(id).and_then(|slot| slot.as_ref())
+    }
+
+    /// Get the weight of the item with a specific id.
+    #[inline]
+    pub fn weight(&self, id: usize) -> Option<f64> {
+        self.get(id).map(|_| self.tree[self.capacity + id])
+    }
+
+    /// Insert an item with its weight and return the id of the item. The ids of removed items are reused.
+    ///
+    /// Returns `WeightError::InvalidWeight` if the weight is negative, infinite or NaN, or it would make the total weight infinite.
+    pub fn insert(&mut self, item: T, weight: f64) -> Result<usize, WeightError> {
+        check_weight(weight)?;
+
+        let id = self.free.last().copied().unwrap_or(self.slots.len());
+
+        // a new slot beyond the capacity is in a new subtree, which is added to the root
+        let total = if id < self.capacity {
+            self.total_weight_with(id, weight)
+        } else {
+            self.total_weight() + weight
+        };
+
+        if !total.is_finite() {
+            return Err(WeightError::InvalidWeight);
+        }
+
+        if id < self.slots.len() {
+            self.free.pop();
+            self.slots[id] = Some(item);
+        } else {
+            if self.slots.len() == self.capacity {
+                self.grow();
+            }
+
+            self.slots.push(Some(item));
+        }
+
+        self.len += 1;
+        self.set(id, weight);
+
+        Ok(id)
+    }
+
+    /// Remove the item with a specific id.
+    pub fn remove(&mut self, id: usize) -> Option<T> {
+        let item = self.slots.get_mut(id).and_then(|slot| slot.take())?;
+
+        self.len -= 1;
+        self.set(id, 0.0);
+        self.free.push(id);
+
+        Some(item)
+    }
+
+    /// Change the weight of the item with a specific id and return the old weight.
+    ///
+    /// Returns `WeightError::UnknownId` if there is no item with the id, or `WeightError::InvalidWeight` if the weight is negative, infinite or NaN, or it would make the total weight infinite.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use random_number::{DynamicWeightedSampler, WeightError};
+    ///
+    /// let mut workers = DynamicWeightedSampler::new();
+    ///
+    /// let a = workers.insert("a", 1.0).unwrap();
+    /// let b = workers.insert("b", f64::MAX).unwrap();
+    ///
+    /// assert_eq!(
+    ///     Err(WeightError::InvalidWeight),
+    ///     workers.update_weight(a, f64::MAX)
+    /// );
+    /// assert_eq!(Err(WeightError::InvalidWeight), workers.insert("c", f64::MAX));
+    /// assert_eq!(Ok(1.0), workers.update_weight(a, 2.0));
+    /// assert!(workers.total_weight().is_finite());
+    ///
+    /// workers.remove(b);
+    ///
+    /// assert_eq!(Err(WeightError::UnknownId), workers.update_weight(b, 1.0));
+    /// assert_eq!(Err(WeightError::UnknownId), workers.update_weight(100, 1.0));
+    /// ```
+    pub fn update_weight(&mut self, id: usize, weight: f64) -> Result<f64, WeightError> {
+        let old_weight = self.weight(id).ok_or(WeightError::UnknownId)?;
+
+        check_weight(weight)?;
+
+        if !self.total_weight_with(id, weight).is_finite() {
+            return Err(WeightError::InvalidWeight);
+        }
+
+        self.set(id, weight);
+
+        Ok(old_weight)
+    }
+
+    /// Sample the id of an item with a new lazily-initialized thread-local random number generator. Returns `None` if the total weight is zero.
+    #[inline]
+    pub fn sample(&self) -> Option<usize> {
+        self.sample_with_rng(&mut default_rng())
+    }
+
+    /// Sample the id of an item with an existing random number generator. Returns `None` if the total weight is zero.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use random_number::DynamicWeightedSampler;
+    ///
+    /// let mut rng = random_number::rand::thread_rng();
+    ///
+    /// let mut workers = DynamicWeightedSampler::new();
+    ///
+    /// let a = workers.insert("a", 1.0).unwrap();
+    /// let b = workers.insert("b", 2.0).unwrap();
+    ///
+    /// workers.update_weight(a, 0.0).unwrap();
+    ///
+    /// for _ in 0..100 {
+    ///     assert_eq!(Some(b), workers.sample_with_rng(&mut rng));
+    /// }
+    ///
+    /// workers.remove(b);
+    ///
+    /// assert_eq!(None, workers.sample_with_rng(&mut rng));
+    /// ```
+    pub fn sample_with_rng<R: Rng>(&self, rng: &mut R) -> Option<usize> {
+        let total = self.total_weight();
+
+        if total <= 0.0 {
+            return None;
+        }
+
+        let mut u = rng.gen::<f64>() * total;
+        let mut node = 1;
+
+        while node < self.capacity {
+            let left = self.tree[node * 2];
+            let right = self.tree[node * 2 + 1];
+
+            // never step into a subtree without weight, even if `u` is off by rounding errors
+            if u < left || right <= 0.0 {
+                node *= 2;
+            } else {
+                u -= left;
+                node = node * 2 + 1;
+            }
+        }
+
+        Some(node - self.capacity)
+    }
+
+    /// The total weight if the slot `id`, which is within the capacity, had a weight of `weight`, summed up in the same way as `set`.
+    fn total_weight_with(&self, id: usize, weight: f64) -> f64 {
+        let mut node = self.capacity + id;
+        let mut sum = weight;
+
+        while node > 1 {
+            sum += self.tree[node ^ 1];
+            node /= 2;
+        }
+
+        sum
+    }
+
+    fn set(&mut self, id: usize, weight: f64) {
+        let mut node = self.capacity + id;
+
+        self.tree[node] = weight;
+
+        while node > 1 {
+            node /= 2;
+
+            self.tree[node] = self.tree[node * 2] + self.tree[node * 2 + 1];
+        }
+    }
+
+    fn grow(&mut self) {
+        let capacity = self.capacity * 2;
+
+        let mut tree = vec![0f64; capacity * 2];
+
+        tree[capacity..(capacity + self.capacity)]
+            .copy_from_slice(&self.tree[self.capacity..(self.capacity * 2)]);
+
+        for node in (1..capacity).rev() {
+            tree[node] = tree[node * 2] + tree[node * 2 + 1];
+        }
+
+        self.tree = tree;
+        self.capacity = capacity;
+    }
+}
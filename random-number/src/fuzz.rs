@@ -0,0 +1,110 @@
+/*!
+An adapter for driving the `_with_rng` functions and the `random!` macro by the input of a fuzzer through [arbitrary](https://crates.io/crates/arbitrary)'s `Unstructured`. This module is available with the `arbitrary` feature.
+
+```rust
+use arbitrary::Unstructured;
+use random_number::{fuzz::UnstructuredRng, random};
+
+let data = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0];
+
+let mut u = Unstructured::new(&data);
+
+let n: u8 = random_number::random_ranged_with_rng(1..=10, &mut UnstructuredRng::new(&mut u));
+assert!((1..=10).contains(&n));
+
+// the same with the macro
+let n: u8 = random!(1..=10, fuzz = u);
+assert!((1..=10).contains(&n));
+
+let mut a = [0i16; 4];
+random_number::random_fill!(a, -5..5, fuzz = u);
+```
+*/
+
+use arbitrary::Unstructured;
+
+use crate::{
+    generators::SplitMix64,
+    rand::{Error, RngCore, SeedableRng},
+};
+
+/// A random number generator which takes its bytes from an `Unstructured`, so the values generated with it are decided by the fuzzer input.
+///
+/// After the `Unstructured` is exhausted, the bytes come from a `SplitMix64` generator with a fixed seed, so the values are still deterministic and functions which reject some outputs and retry cannot loop forever on the same bytes.
+///
+/// In the `random!` and `random_fill!` macros, `fuzz = u` takes the place of the random number generator and wraps `&mut u` in this adapter. If `u` is a `&mut Unstructured`, write `fuzz = *u`.
+///
+/// ## Examples
+///
+/// ```rust
+/// use arbitrary::Unstructured;
+/// use random_number::{fuzz::UnstructuredRng, rand::RngCore};
+///
+/// let mut u = Unstructured::new(&[1, 0, 0, 0, 2]);
+/// let mut rng = UnstructuredRng::new(&mut u);
+///
+/// assert_eq!(1, rng.next_u32());
+///
+/// let mut bytes = [0u8; 4];
+/// rng.fill_bytes(&mut bytes);
+///
+/// // the last byte of the input, then bytes of the fallback generator
+/// assert_eq!(2, bytes[0]);
+/// ```
+#[derive(Debug)]
+pub struct UnstructuredRng<'a, 'b> {
+    u:        &'b mut Unstructured<'a>,
+    fallback: SplitMix64,
+}
+
+impl<'a, 'b> UnstructuredRng<'a, 'b> {
+    /// Create a generator which takes its bytes from `u`.
+    #[inline]
+    pub fn new(u: &'b mut Unstructured<'a>) -> UnstructuredRng<'a, 'b> {
+        UnstructuredRng {
+            u,
+            fallback: SplitMix64::seed_from_u64(0),
+        }
+    }
+}
+
+impl<'a, 'b> RngCore for UnstructuredRng<'a, 'b> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+
+        self.fill_bytes(&mut bytes);
+
+        u32::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+
+        self.fill_bytes(&mut bytes);
+
+        u64::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let n = dest.len().min(self.u.len());
+
+        let (head, tail) = dest.split_at_mut(n);
+
+        // `n` bytes are left, so this cannot fail
+        head.copy_from_slice(self.u.bytes(n).unwrap());
+
+        if !tail.is_empty() {
+            self.fallback.fill_bytes(tail);
+        }
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+
+        Ok(())
+    }
+}
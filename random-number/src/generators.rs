@@ -0,0 +1,761 @@
+/*!
+Small and fast pseudorandom number generators which are shipped by this crate, without extra dependencies.
+
+They are **not** cryptographically secure. Every generator implements `RngCore` and `SeedableRng`, so it can be passed as the last argument of the `random!` and `random_fill!` macros, or to any `_with_rng` function. Every generator also implements `SplittableRng`, which derives independent streams for parallel and reproducible generation.
+
+```rust
+use random_number::{generators::Xoshiro256StarStar, rand::SeedableRng};
+
+let mut rng = Xoshiro256StarStar::seed_from_u64(42);
+
+let n: u8 = random_number::random!(1..=6, rng);
+println!("{}", n); // 1 ~ 6, the same every time
+```
+*/
+
+use crate::rand::{Error, RngCore, SeedableRng};
+
+#[inline]
+fn fill_bytes_via_u64(dest: &mut [u8], mut next_u64: impl FnMut() -> u64) {
+    let mut chunks = dest.chunks_exact_mut(8);
+
+    for chunk in chunks.by_ref() {
+        chunk.copy_from_slice(&next_u64().to_le_bytes());
+    }
+
+    let remainder = chunks.into_remainder();
+
+    if !remainder.is_empty() {
+        let n = remainder.len();
+
+        remainder.copy_from_slice(&next_u64().to_le_bytes()[..n]);
+    }
+}
+
+#[inline]
+fn fill_bytes_via_u32(dest: &mut [u8], mut next_u32: impl FnMut() -> u32) {
+    let mut chunks = dest.chunks_exact_mut(4);
+
+    for chunk in chunks.by_ref() {
+        chunk.copy_from_slice(&next_u32().to_le_bytes());
+    }
+
+    let remainder = chunks.into_remainder();
+
+    if !remainder.is_empty() {
+        let n = remainder.len();
+
+        remainder.copy_from_slice(&next_u32().to_le_bytes()[..n]);
+    }
+}
+
+#[inline]
+fn read_u64(bytes: &[u8]) -> u64 {
+    let mut buffer = [0u8; 8];
+
+    buffer.copy_from_slice(&bytes[..8]);
+
+    u64::from_le_bytes(buffer)
+}
+
+/// The output function of SplitMix64, a bijective mix of 64 bits.
+#[inline]
+pub(crate) fn mix64(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+
+    z ^ (z >> 31)
+}
+
+/// The number of steps skipped by `SplittableRng::jump` for the generators with 64 bits of state.
+const JUMP_64: u64 = 1 << 48;
+
+/// A random number generator whose sequence can be divided into independent streams, so that parallel workers get different random numbers and any part of a simulation can be regenerated on its own.
+///
+/// ```rust
+/// use random_number::{
+///     generators::{SplittableRng, Xoshiro256StarStar},
+///     rand::{RngCore, SeedableRng},
+/// };
+///
+/// let root = Xoshiro256StarStar::seed_from_u64(42);
+///
+/// // a fork depends only on the parent and the stream id
+/// assert_eq!(root.fork(7), root.fork(7));
+/// assert_ne!(root.fork(7).next_u64(), root.fork(8).next_u64());
+///
+/// // jumping twice leads where a jumped copy jumps once
+/// let mut a = root.clone();
+/// a.jump();
+///
+/// let mut b = a.clone();
+/// b.jump();
+/// a.jump();
+///
+/// assert_eq!(a, b);
+/// ```
+pub trait SplittableRng: RngCore + Sized {
+    /// Create a new generator for another stream, and advance this generator so that the two of them do not share their next outputs.
+    fn split(&mut self) -> Self;
+
+    /// Advance the generator by a fixed large number of steps, which divides the period into non-overlapping subsequences. The number is documented on each generator.
+    fn jump(&mut self);
+
+    /// Derive the generator of the stream `stream_id` without changing this generator. The same generator and the same stream id always give the same stream.
+    fn fork(&self, stream_id: u64) -> Self;
+}
+
+/// SplitMix64 by Sebastiano Vigna, a 64-bit generator with 64 bits of state. Every seed is fine, so it is also used to expand a `u64` seed into the states of other generators.
+///
+/// ```rust
+/// use random_number::{
+///     generators::SplitMix64,
+///     rand::{RngCore, SeedableRng},
+/// };
+///
+/// // the reference outputs of the seed 1234567
+/// let mut rng = SplitMix64::seed_from_u64(1234567);
+///
+/// assert_eq!(6457827717110365317, rng.next_u64());
+/// assert_eq!(3203168211198807973, rng.next_u64());
+/// assert_eq!(9817491932198370423, rng.next_u64());
+/// assert_eq!(4593380528125082431, rng.next_u64());
+/// assert_eq!(16408922859458223821, rng.next_u64());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+const SPLITMIX64_GAMMA: u64 = 0x9E3779B97F4A7C15;
+
+impl SplitMix64 {
+    /// Advance the generator by `delta` steps in constant time.
+    #[inline]
+    pub fn advance(&mut self, delta: u64) {
+        self.state = self.state.wrapping_add(SPLITMIX64_GAMMA.wrapping_mul(delta));
+    }
+}
+
+impl RngCore for SplitMix64 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(SPLITMIX64_GAMMA);
+
+        mix64(self.state)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes_via_u64(dest, || self.next_u64())
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+
+        Ok(())
+    }
+}
+
+impl SeedableRng for SplitMix64 {
+    type Seed = [u8; 8];
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        SplitMix64 {
+            state: u64::from_le_bytes(seed)
+        }
+    }
+
+    #[inline]
+    fn seed_from_u64(state: u64) -> Self {
+        SplitMix64 {
+            state,
+        }
+    }
+}
+
+/// `jump` advances the generator by 2<sup>48</sup> steps.
+impl SplittableRng for SplitMix64 {
+    #[inline]
+    fn split(&mut self) -> Self {
+        SplitMix64 {
+            state: self.next_u64()
+        }
+    }
+
+    #[inline]
+    fn jump(&mut self) {
+        self.advance(JUMP_64);
+    }
+
+    #[inline]
+    fn fork(&self, stream_id: u64) -> Self {
+        SplitMix64 {
+            state: mix64(self.state ^ mix64(stream_id))
+        }
+    }
+}
+
+/// xoshiro256** by David Blackman and Sebastiano Vigna, an all-purpose 64-bit generator with 256 bits of state and a period of 2<sup>256</sup> - 1.
+///
+/// ```rust
+/// use random_number::{
+///     generators::Xoshiro256StarStar,
+///     rand::{RngCore, SeedableRng},
+/// };
+///
+/// // the reference outputs of the state [1, 2, 3, 4]
+/// let mut seed = [0u8; 32];
+///
+/// for (i, chunk) in seed.chunks_exact_mut(8).enumerate() {
+///     chunk.copy_from_slice(&(i as u64 + 1).to_le_bytes());
+/// }
+///
+/// let mut rng = Xoshiro256StarStar::from_seed(seed);
+///
+/// assert_eq!(11520, rng.next_u64());
+/// assert_eq!(0, rng.next_u64());
+/// assert_eq!(1509978240, rng.next_u64());
+/// assert_eq!(1215971899390074240, rng.next_u64());
+/// assert_eq!(1216172134540287360, rng.next_u64());
+/// assert_eq!(607988272756665600, rng.next_u64());
+/// assert_eq!(16172922978634559625, rng.next_u64());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Xoshiro256StarStar {
+    s: [u64; 4],
+}
+
+impl Xoshiro256StarStar {
+    /// Advance the generator by 2<sup>128</sup> steps, which splits the period into 2<sup>128</sup> non-overlapping subsequences, for example, one for each thread.
+    pub fn jump(&mut self) {
+        const JUMP: [u64; 4] =
+            [0x180EC6D33CFD0ABA, 0xD5A61266F0C9392C, 0xA9582618E03FC9AA, 0x39ABDC4529B1661C];
+
+        let mut s = [0u64; 4];
+
+        for jump in JUMP {
+            for b in 0..64 {
+                if jump & (1 << b) != 0 {
+                    for (s, t) in s.iter_mut().zip(self.s) {
+                        *s ^= t;
+                    }
+                }
+
+                self.next_u64();
+            }
+        }
+
+        self.s = s;
+    }
+}
+
+impl RngCore for Xoshiro256StarStar {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let result = self.s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+
+        let t = self.s[1] << 17;
+
+        self.s[2] ^= self.s[0];
+        self.s[3] ^= self.s[1];
+        self.s[1] ^= self.s[2];
+        self.s[0] ^= self.s[3];
+
+        self.s[2] ^= t;
+
+        self.s[3] = self.s[3].rotate_left(45);
+
+        result
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes_via_u64(dest, || self.next_u64())
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+
+        Ok(())
+    }
+}
+
+impl SeedableRng for Xoshiro256StarStar {
+    type Seed = [u8; 32];
+
+    /// Create a generator from the little-endian bytes of its state. An all-zero state, which would only output zeros, is replaced by `seed_from_u64(0)`.
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        if seed.iter().all(|&b| b == 0) {
+            return Self::seed_from_u64(0);
+        }
+
+        let mut s = [0u64; 4];
+
+        for (s, chunk) in s.iter_mut().zip(seed.chunks_exact(8)) {
+            *s = read_u64(chunk);
+        }
+
+        Xoshiro256StarStar {
+            s,
+        }
+    }
+
+    /// Create a generator whose state is expanded from a `u64` by SplitMix64, as recommended by the authors.
+    #[inline]
+    fn seed_from_u64(state: u64) -> Self {
+        let mut rng = SplitMix64::seed_from_u64(state);
+
+        Xoshiro256StarStar {
+            s: [rng.next_u64(), rng.next_u64(), rng.next_u64(), rng.next_u64()]
+        }
+    }
+}
+
+/// `jump` advances the generator by 2<sup>128</sup> steps. `split` returns a copy of this generator and jumps this one, so up to 2<sup>128</sup> generators split in a row never overlap.
+impl SplittableRng for Xoshiro256StarStar {
+    #[inline]
+    fn split(&mut self) -> Self {
+        let rng = self.clone();
+
+        Xoshiro256StarStar::jump(self);
+
+        rng
+    }
+
+    #[inline]
+    fn jump(&mut self) {
+        Xoshiro256StarStar::jump(self)
+    }
+
+    #[inline]
+    fn fork(&self, stream_id: u64) -> Self {
+        let mut rng = SplitMix64::seed_from_u64(mix64(stream_id));
+
+        let mut s = self.s;
+
+        for s in s.iter_mut() {
+            *s ^= rng.next_u64();
+        }
+
+        // an all-zero state would only output zeros
+        if s == [0; 4] {
+            s = self.s;
+        }
+
+        Xoshiro256StarStar {
+            s,
+        }
+    }
+}
+
+/// PCG32 (PCG-XSH-RR 64/32) by Melissa O'Neill, a 32-bit generator with 64 bits of state and 2<sup>63</sup> selectable streams.
+///
+/// ```rust
+/// use random_number::{generators::Pcg32, rand::RngCore};
+///
+/// // the reference outputs of `pcg32-demo`, whose state is 42 and stream is 54
+/// let mut rng = Pcg32::new(42, 54);
+///
+/// assert_eq!(0xA15C02B7, rng.next_u32());
+/// assert_eq!(0x7B47F409, rng.next_u32());
+/// assert_eq!(0xBA1D3330, rng.next_u32());
+/// assert_eq!(0x83D2F293, rng.next_u32());
+/// assert_eq!(0xBFA4784B, rng.next_u32());
+/// assert_eq!(0xCBED606E, rng.next_u32());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pcg32 {
+    state:     u64,
+    increment: u64,
+}
+
+const PCG32_MULTIPLIER: u64 = 6364136223846793005;
+
+impl Pcg32 {
+    /// Create a generator with an initial state and a stream selector. Generators on different streams produce different sequences even with the same state.
+    #[inline]
+    pub fn new(state: u64, stream: u64) -> Self {
+        let increment = (stream << 1) | 1;
+
+        let mut rng = Pcg32 {
+            state: state.wrapping_add(increment),
+            increment,
+        };
+
+        rng.step();
+
+        rng
+    }
+
+    /// Advance the generator by `delta` steps in O(log `delta`) time.
+    pub fn advance(&mut self, mut delta: u64) {
+        // compose the affine steps `x * m + c` by squaring, from the lowest bit of `delta`
+        let mut multiplier = PCG32_MULTIPLIER;
+        let mut increment = self.increment;
+
+        let mut total_multiplier = 1u64;
+        let mut total_increment = 0u64;
+
+        while delta > 0 {
+            if delta & 1 == 1 {
+                total_multiplier = total_multiplier.wrapping_mul(multiplier);
+                total_increment = total_increment.wrapping_mul(multiplier).wrapping_add(increment);
+            }
+
+            increment = multiplier.wrapping_add(1).wrapping_mul(increment);
+            multiplier = multiplier.wrapping_mul(multiplier);
+
+            delta >>= 1;
+        }
+
+        self.state = total_multiplier.wrapping_mul(self.state).wrapping_add(total_increment);
+    }
+
+    #[inline]
+    fn step(&mut self) {
+        self.state = self.state.wrapping_mul(PCG32_MULTIPLIER).wrapping_add(self.increment);
+    }
+}
+
+impl RngCore for Pcg32 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        let state = self.state;
+
+        self.step();
+
+        let rotation = (state >> 59) as u32;
+        let xor_shifted = (((state >> 18) ^ state) >> 27) as u32;
+
+        xor_shifted.rotate_right(rotation)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let low = u64::from(self.next_u32());
+        let high = u64::from(self.next_u32());
+
+        (high << 32) | low
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes_via_u32(dest, || self.next_u32())
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+
+        Ok(())
+    }
+}
+
+impl SeedableRng for Pcg32 {
+    /// The little-endian bytes of the state followed by those of the stream.
+    type Seed = [u8; 16];
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        Pcg32::new(read_u64(&seed[..8]), read_u64(&seed[8..]))
+    }
+}
+
+/// `jump` advances the generator by 2<sup>48</sup> steps. `split` and `fork` move to another of the 2<sup>63</sup> streams, and `fork` gives different streams to different stream ids below 2<sup>63</sup>.
+impl SplittableRng for Pcg32 {
+    #[inline]
+    fn split(&mut self) -> Self {
+        let state = self.next_u64();
+        let stream = self.next_u64();
+
+        Pcg32::new(state, stream)
+    }
+
+    #[inline]
+    fn jump(&mut self) {
+        self.advance(JUMP_64);
+    }
+
+    #[inline]
+    fn fork(&self, stream_id: u64) -> Self {
+        Pcg32::new(mix64(self.state ^ mix64(stream_id)), (self.increment >> 1) ^ stream_id)
+    }
+}
+
+/// WyRand by Wang Yi, one of the fastest 64-bit generators, with 64 bits of state.
+///
+/// ```rust
+/// use random_number::{
+///     generators::WyRand,
+///     rand::{RngCore, SeedableRng},
+/// };
+///
+/// // the outputs of the reference `wyrand` with the seed 0
+/// let mut rng = WyRand::seed_from_u64(0);
+///
+/// assert_eq!(0x111CB3A78F59A58E, rng.next_u64());
+/// assert_eq!(0xCEABD938FF4E856D, rng.next_u64());
+/// assert_eq!(0x61FB51318F47D2A4, rng.next_u64());
+/// assert_eq!(0x78BD03C491909760, rng.next_u64());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WyRand {
+    state: u64,
+}
+
+const WYRAND_INCREMENT: u64 = 0xA0761D6478BD642F;
+
+impl WyRand {
+    /// Advance the generator by `delta` steps in constant time.
+    #[inline]
+    pub fn advance(&mut self, delta: u64) {
+        self.state = self.state.wrapping_add(WYRAND_INCREMENT.wrapping_mul(delta));
+    }
+}
+
+impl RngCore for WyRand {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(WYRAND_INCREMENT);
+
+        let t = u128::from(self.state) * u128::from(self.state ^ 0xE7037ED1A0B428DB);
+
+        (t as u64) ^ ((t >> 64) as u64)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes_via_u64(dest, || self.next_u64())
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+
+        Ok(())
+    }
+}
+
+impl SeedableRng for WyRand {
+    type Seed = [u8; 8];
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        WyRand {
+            state: u64::from_le_bytes(seed)
+        }
+    }
+
+    #[inline]
+    fn seed_from_u64(state: u64) -> Self {
+        WyRand {
+            state,
+        }
+    }
+}
+
+/// `jump` advances the generator by 2<sup>48</sup> steps.
+impl SplittableRng for WyRand {
+    #[inline]
+    fn split(&mut self) -> Self {
+        WyRand {
+            state: self.next_u64()
+        }
+    }
+
+    #[inline]
+    fn jump(&mut self) {
+        self.advance(JUMP_64);
+    }
+
+    #[inline]
+    fn fork(&self, stream_id: u64) -> Self {
+        WyRand {
+            state: mix64(self.state ^ mix64(stream_id))
+        }
+    }
+}
+
+/// Philox4x32-10 by John Salmon, Mark Moraes, Ron Dror and David Shaw, a counter-based generator from the Random123 library. Each block of four `u32` outputs is a pure function of a 64-bit key and a 128-bit counter, so any position of the sequence can be reached in constant time.
+///
+/// ```rust
+/// use random_number::generators::Philox4x32;
+///
+/// // the known-answer tests of Random123
+/// assert_eq!(
+///     [0x6627E8D5, 0xE169C58D, 0xBC57AC4C, 0x9B00DBD8],
+///     Philox4x32::block(0, 0)
+/// );
+///
+/// assert_eq!(
+///     [0xD16CFE09, 0x94FDCCEB, 0x5001E420, 0x24126EA1],
+///     Philox4x32::block(
+///         0x299F31D0A4093822,
+///         0x0370734413198A2E85A308D3243F6A88
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Philox4x32 {
+    key:     u64,
+    counter: u128,
+    buffer:  [u32; 4],
+    index:   usize,
+}
+
+impl Philox4x32 {
+    /// Create a generator whose first output is the first word of the block `counter`.
+    #[inline]
+    pub fn new(key: u64, counter: u128) -> Self {
+        Philox4x32 {
+            key,
+            counter,
+            buffer: [0; 4],
+            index: 4,
+        }
+    }
+
+    /// Compute the block of the counter `counter` with the key `key`. The words of the key and the counter are in little-endian order.
+    pub fn block(key: u64, counter: u128) -> [u32; 4] {
+        const M0: u64 = 0xD2511F53;
+        const M1: u64 = 0xCD9E8D57;
+        const W0: u32 = 0x9E3779B9;
+        const W1: u32 = 0xBB67AE85;
+
+        let mut k = [key as u32, (key >> 32) as u32];
+        let mut c = [
+            counter as u32,
+            (counter >> 32) as u32,
+            (counter >> 64) as u32,
+            (counter >> 96) as u32,
+        ];
+
+        for round in 0..10 {
+            if round > 0 {
+                k[0] = k[0].wrapping_add(W0);
+                k[1] = k[1].wrapping_add(W1);
+            }
+
+            let p0 = M0 * u64::from(c[0]);
+            let p1 = M1 * u64::from(c[2]);
+
+            c = [
+                (p1 >> 32) as u32 ^ c[1] ^ k[0],
+                p1 as u32,
+                (p0 >> 32) as u32 ^ c[3] ^ k[1],
+                p0 as u32,
+            ];
+        }
+
+        c
+    }
+
+    /// The counter of the block which the next output is taken from.
+    #[inline]
+    pub fn counter(&self) -> u128 {
+        if self.index < 4 {
+            self.counter.wrapping_sub(1)
+        } else {
+            self.counter
+        }
+    }
+
+    /// Move to the first word of the block `counter`.
+    #[inline]
+    pub fn seek(&mut self, counter: u128) {
+        self.counter = counter;
+        self.index = 4;
+    }
+}
+
+impl RngCore for Philox4x32 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        if self.index >= 4 {
+            self.buffer = Philox4x32::block(self.key, self.counter);
+            self.counter = self.counter.wrapping_add(1);
+            self.index = 0;
+        }
+
+        let result = self.buffer[self.index];
+
+        self.index += 1;
+
+        result
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let low = u64::from(self.next_u32());
+        let high = u64::from(self.next_u32());
+
+        (high << 32) | low
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes_via_u32(dest, || self.next_u32())
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+
+        Ok(())
+    }
+}
+
+impl SeedableRng for Philox4x32 {
+    /// The little-endian bytes of the key. The counter starts from 0.
+    type Seed = [u8; 8];
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        Philox4x32::new(u64::from_le_bytes(seed), 0)
+    }
+
+    #[inline]
+    fn seed_from_u64(key: u64) -> Self {
+        Philox4x32::new(key, 0)
+    }
+}
+
+/// `jump` moves to the block 2<sup>64</sup> after the current one. `split` and `fork` switch to another key.
+impl SplittableRng for Philox4x32 {
+    #[inline]
+    fn split(&mut self) -> Self {
+        Philox4x32::new(self.next_u64(), 0)
+    }
+
+    #[inline]
+    fn jump(&mut self) {
+        let counter = self.counter().wrapping_add(1 << 64);
+
+        self.seek(counter);
+    }
+
+    #[inline]
+    fn fork(&self, stream_id: u64) -> Self {
+        Philox4x32::new(mix64(self.key ^ mix64(stream_id)), self.counter())
+    }
+}
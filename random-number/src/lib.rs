@@ -90,17 +90,542 @@ random_number::random_fill_ranged(&mut a, var_range);
 
 println!("{:?}", a);
 ```
+
+### Tuples and Arrays
+
+If both bounds of the range are tuples or arrays, every component is generated in its own range.
+
+```rust
+let (x, y): (u8, i16) = random_number::random!((0, -5)..=(10, 5));
+println!("({}, {})", x, y); // 0 ~ 10, -5 ~ 5
+
+let a: [u8; 4] = random_number::random!([0; 4]..[10; 4]);
+println!("{:?}", a); // 0 ~ 9 each
+```
+
+### Booleans
+
+Use `random!(bool, p = ...)` or the `random_bool` function to get `true` with a probability, and the `random_ratio` function for an exact fraction without floating-point numbers. The `random_bitset` function packs many random bits into `u64` words efficiently.
+
+```rust
+let enabled = random_number::random!(bool, p = 0.3);
+println!("{}", enabled); // true with a probability of 30%
+
+let enabled = random_number::random_ratio(1, 3);
+println!("{}", enabled); // true with a probability of exactly 1/3
+
+let mut flags = [false; 16];
+random_number::random_fill!(flags, bool, p = 0.1);
+
+println!("{:?}", flags);
+
+let bits = random_number::random_bitset(1000, 0.25);
+println!("{}", bits.iter().map(|word| word.count_ones()).sum::<u32>()); // about 250
+```
+
+### Excluding Values
+
+Add an `except [...]` clause after the range in the `random!` and `random_fill!` macros, or use the `random_except` and `random_fill_except` functions, to exclude some values or sub-ranges. The remaining values are sampled uniformly without retrying.
+
+```rust
+let n: u8 = random_number::random!(1..=10, except [3, 5..7]);
+println!("{}", n); // 1 ~ 10 except 3, 5 and 6
+
+let used_ports = [8080, 8443];
+
+let n: u16 = random_number::random_except(1024.., &used_ports);
+println!("{}", n); // 1024 ~ 65535 except 8080 and 8443
+
+let mut a = [0i8; 32];
+random_number::random_fill_except(&mut a, -10..=10, &[-2..=2]);
+
+println!("{:?}", a);
+```
+
+### Weighted Values
+
+Add a `weights = [...]` clause after an integer range in the `random!` and `random_fill!` macros, or use the `random_weighted_ranged` and `random_fill_weighted_ranged` functions, to give each value of the range its own weight. The `i`-th weight belongs to the `i`-th value of the range. Sampling takes constant time by using an alias table, which the macros build only once per thread if every weight is a literal.
+
+```rust
+let n: u8 = random_number::random!(1..=6, weights = [1, 1, 1, 1, 1, 5]);
+println!("{}", n); // 1 ~ 6, and 6 is as likely as the others together
+
+let weights = [0.5, 0.3, 0.2];
+
+let mut a = [0i32; 32];
+random_number::random_fill_weighted_ranged(&mut a, -1..=1, &weights);
+
+println!("{:?}", a);
+```
+
+For drawing many times from the same distribution of arbitrary items, build a `WeightedTable` once from `(item, weight)` pairs or a `HashMap`.
+
+```rust
+use random_number::WeightedTable;
+
+let table = WeightedTable::new(vec![("sword", 1.0), ("shield", 2.0), ("potion", 7.0)]).unwrap();
+
+let item = table.sample();
+println!("{}", item);
+
+let items: Vec<&str> = table.iter().take(10).copied().collect();
+println!("{:?}", items);
+```
+
+When the weights keep changing, for example, to pick workers by their load, use a `DynamicWeightedSampler`. Inserting, removing, updating a weight and sampling all take O(log n) time.
+
+```rust
+use random_number::DynamicWeightedSampler;
+
+let mut workers = DynamicWeightedSampler::new();
+
+let a = workers.insert("worker-a", 4.0).unwrap();
+let b = workers.insert("worker-b", 1.0).unwrap();
+
+workers.update_weight(a, 0.5).unwrap();
+
+if let Some(id) = workers.sample() {
+    println!("{}", workers.get(id).unwrap());
+}
+
+workers.remove(b);
+```
+
+### Sampling from Streams
+
+The `random_sample_iter` function picks `k` items from an iterator whose length is unknown in advance, such as the lines of a log stream, by reservoir sampling. The `random_sample_iter_weighted` function does the same with weighted items, and a `Reservoir` can be fed one item at a time.
+
+```rust
+let log = "GET /\nGET /a\nPOST /b\nGET /c\nDELETE /d";
+
+let lines = random_number::random_sample_iter(log.lines(), 2);
+println!("{:?}", lines);
+
+let servers = vec![("a", 5.0), ("b", 1.0), ("c", 1.0)];
+
+let picked = random_number::random_sample_iter_weighted(servers, 2).unwrap();
+println!("{:?}", picked);
+
+let mut reservoir = random_number::Reservoir::new(2);
+
+for line in log.lines() {
+    reservoir.push(line);
+}
+
+println!("{:?}", reservoir.items());
+```
+
+### Series
+
+The `random_walk_fill` function generates a random walk instead of independent values, which looks more like real-world metrics. The walk can be clamped or reflected at its bounds. Geometric Brownian motion fits prices, and the Ornstein–Uhlenbeck process fits values which fluctuate around a typical level. Every series is also available as an endless iterator.
+
+```rust
+use random_number::Boundary;
+
+let mut requests = [0i32; 60];
+random_number::random_walk_fill_bounded(&mut requests, 100, -10..=10, Boundary::Reflect(0, 200));
+
+println!("{:?}", requests);
+
+let mut prices = [0f64; 252];
+random_number::random_geometric_brownian_motion_fill(&mut prices, 100.0, 0.05, 0.2, 1.0 / 252.0);
+
+println!("{:?}", prices);
+
+let latencies: Vec<f64> = random_number::random_ornstein_uhlenbeck(50.0, 50.0, 0.5, 5.0, 1.0).take(60).collect();
+println!("{:?}", latencies);
+```
+
+### Markov Chains
+
+A `MarkovChain` generates sequences of states, such as the events of a user session, from weighted `(from, to, weight)` transitions. It can also be parsed from text with one `from to weight` transition per line.
+
+```rust
+use random_number::MarkovChain;
+
+let chain = MarkovChain::new(vec![
+    ("home", "search", 3.0),
+    ("home", "cart", 1.0),
+    ("search", "home", 1.0),
+    ("search", "cart", 1.0),
+    ("cart", "checkout", 1.0),
+])
+.unwrap();
+
+let session = chain.walk(&"home", 20);
+println!("{:?}", session); // ends with "checkout" unless it has 20 events
+
+let chain: MarkovChain<String> = "sunny sunny 0.8\nsunny rainy 0.2\nrainy sunny 0.4\nrainy rainy 0.6".parse().unwrap();
+
+let mut week = vec![String::new(); 7];
+chain.fill(&mut week, &"sunny".to_string());
+
+println!("{:?}", week);
+```
+
+### Distinct Values
+
+The `random_fill_unique` and `random_unique_vec` functions generate values which are all different from each other. An error is returned if the range does not contain enough values.
+
+```rust
+let ids: Vec<u32> = random_number::random_unique_vec(10, 1..=1000).unwrap();
+println!("{:?}", ids);
+
+let mut a = [0u8; 10];
+random_number::random_fill_unique(&mut a, 1..=10).unwrap();
+
+println!("{:?}", a); // a permutation of 1 ~ 10
+```
+
+### Sorted Values
+
+The `random_fill_sorted` function generates values which are already sorted in ascending order, without sorting them afterwards. The `random_fill_sorted_unique` function additionally makes them distinct.
+
+```rust
+let mut timestamps = [0u64; 32];
+random_number::random_fill_sorted(&mut timestamps, 1_600_000_000..1_700_000_000);
+
+println!("{:?}", timestamps);
+
+let mut offsets = [0f64; 32];
+random_number::random_fill_sorted(&mut offsets, 0.0..=10.0);
+
+println!("{:?}", offsets);
+```
+
+### Values with a Fixed Sum
+
+The `random_fill_with_sum` and `random_fill_with_sum_ranged` functions generate values whose sum is a given total, for example, to split a quota.
+
+```rust
+let mut quotas = [0u32; 8];
+random_number::random_fill_with_sum(&mut quotas, 1000).unwrap();
+
+println!("{:?}", quotas); // the sum is 1000
+
+let mut quotas = [0u32; 8];
+random_number::random_fill_with_sum_ranged(&mut quotas, 1000, 100..=150).unwrap();
+
+println!("{:?}", quotas); // 100 ~ 150 each, and the sum is 1000
+```
+
+### Points
+
+Random points can be generated in a box, in a ball, on the surface of a sphere, or in a triangle, in any number of dimensions.
+
+```rust
+let [x, y] = random_number::random_point_in_box([0.0, -1.0], [10.0, 1.0]);
+println!("({}, {})", x, y); // 0.0 ~ 10.0, -1.0 ~ 1.0
+
+let [x, y, z] = random_number::random_point_in_ball(5.0);
+println!("({}, {}, {})", x, y, z); // within a distance of 5.0 from the origin
+
+let [x, y, z] = random_number::random_point_on_sphere();
+println!("({}, {}, {})", x, y, z); // at a distance of 1.0 from the origin
+
+let mut points = [[0f64; 2]; 16];
+random_number::random_fill_points_in_triangle(&mut points, [0.0, 0.0], [4.0, 0.0], [0.0, 3.0]);
+
+println!("{:?}", points);
+```
+
+### Random Bytes
+
+The `random_bytes` and `random_bytes_array` functions generate bytes directly from the underlying random number generator, which is handy for nonces and buffers. Filling a `u8` slice by using the `random_fill!` macro without a range takes the same fast path.
+
+```rust
+let nonce: [u8; 12] = random_number::random_bytes_array();
+println!("{:?}", nonce);
+
+let buffer = random_number::random_bytes(32);
+println!("{:?}", buffer);
+
+let mut a = [0u8; 32];
+random_number::random_fill!(a);
+
+println!("{:?}", a);
+```
+
+### IP Addresses
+
+`Ipv4Addr` and `Ipv6Addr` do not implement `SampleUniform`, but the `random!` macro accepts ranges of them, and so do the `random_ipv4_ranged` and `random_ipv6_ranged` functions. An address can also be picked from a CIDR block.
+
+```rust
+use std::net::Ipv4Addr;
+
+use random_number::IpExclusion;
+
+let ip: Ipv4Addr = random_number::random!(Ipv4Addr::new(10, 0, 0, 0)..=Ipv4Addr::new(10, 255, 255, 255));
+println!("{}", ip); // 10.0.0.0 ~ 10.255.255.255
+
+let network = Ipv4Addr::new(172, 16, 0, 0)..=Ipv4Addr::new(172, 31, 255, 255);
+
+let ip = random_number::random_ipv4_ranged(network);
+println!("{}", ip); // 172.16.0.0 ~ 172.31.255.255
+
+let ip = random_number::random_in_cidr("192.168.0.0/16").unwrap();
+println!("{}", ip); // 192.168.0.0 ~ 192.168.255.255
+
+let ip = random_number::random_in_cidr_excluding("192.168.1.0/24", IpExclusion {
+    network_and_broadcast: true,
+    ..IpExclusion::default()
+})
+.unwrap();
+println!("{}", ip); // 192.168.1.1 ~ 192.168.1.254
+
+let addr = random_number::random_socket_addr("2001:db8::/32", 1024..).unwrap();
+println!("{}", addr); // [2001:db8::]:1024 ~ [2001:db8:ffff:ffff:ffff:ffff:ffff:ffff]:65535
+```
+
+### Statistical Diagnostics
+
+The `diagnostics` module provides the chi-square, Kolmogorov-Smirnov and runs tests, which are used by this crate's own test suite to check that ranged sampling is unbiased for every primitive type, and can check any generated output.
+
+```rust
+use random_number::diagnostics;
+
+let mut a = [0f64; 10000];
+random_number::random_fill!(a, 0.0..1.0);
+
+let result = diagnostics::kolmogorov_smirnov_test(&a, |x| x);
+println!("{:?}", result); // TestResult { statistic: ..., p_value: ... }
+
+let result = diagnostics::runs_test(&a);
+println!("{:?}", result);
+```
+
+### Built-in Generators
+
+The `generators` module provides small and fast generators which need no extra crates: `Xoshiro256StarStar`, `SplitMix64`, `Pcg32`, `WyRand` and the counter-based `Philox4x32`. They are handy for reproducible games and simulations, but they are not cryptographically secure.
+
+```rust
+use random_number::{generators::WyRand, rand::SeedableRng};
+
+let mut rng = WyRand::seed_from_u64(42);
+
+let n: u8 = random_number::random!(1..=6, rng);
+println!("{}", n); // 1 ~ 6, the same every time
+
+let mut a = [0u16; 8];
+random_number::random_fill!(a, 100..200, rng);
+
+println!("{:?}", a);
+```
+
+### Parallel and Reproducible Streams
+
+Every built-in generator implements the `SplittableRng` trait. `split` creates a generator for another stream, `jump` skips a fixed large number of steps, and `fork(stream_id)` derives the stream of an id without changing the parent, so each worker of a distributed simulation can rebuild its own stream from the shared seed.
+
+The `random_fill_chunked` and `random_fill_chunked_ranged` functions give each chunk of a slice its own stream, `chunk_rng(seed, chunk_index)`, so any shard can be regenerated independently.
+
+```rust
+use random_number::{
+    generators::{SplittableRng, Xoshiro256StarStar},
+    rand::SeedableRng,
+};
+
+let root = Xoshiro256StarStar::seed_from_u64(42);
+
+let mut worker_rng = root.fork(3);
+let n: u8 = random_number::random!(worker_rng);
+println!("{}", n); // the same every time for the worker 3
+
+let mut a = [0u32; 1000];
+random_number::random_fill_chunked(&mut a, 100, 42);
+
+let mut shard = [0u32; 100];
+random_number::random_fill_with_rng(&mut shard, &mut random_number::chunk_rng(42, 7));
+
+assert_eq!(&a[700..800], &shard[..]);
+```
+
+### Random Access
+
+The `random_at_index` function computes the value at an index of a virtual random array without generating the values before it, and the `random_fill_from_index` function regenerates any window of the array in O(window). They are built on the counter-based `Philox4x32` generator, which is handy for procedural world generation.
+
+```rust
+let height: u8 = random_number::random_at_index(2024, 1_000_000, 0..=100);
+println!("{}", height); // 0 ~ 100, the same every time
+
+let mut window = [0u8; 16];
+random_number::random_fill_from_index(&mut window, 2024, 999_992, 0..=100);
+
+assert_eq!(height, window[8]);
+```
+
+### Noise
+
+The `noise` module provides seeded value, Perlin and OpenSimplex noise in 1D, 2D and 3D, `Fbm` to add octaves of a noise together, and the `noise_fill_2d` function to fill a height map.
+
+```rust
+use random_number::noise::{Fbm, Noise, NoiseKind, NoiseParams, OpenSimplexNoise};
+
+let noise = Fbm::new(OpenSimplexNoise::new(42)).octaves(6).persistence(0.4);
+println!("{}", noise.sample_3d(1.5, 2.5, 3.5)); // -1 ~ 1, the same every time
+
+let params = NoiseParams {
+    kind: NoiseKind::Perlin,
+    seed: 42,
+    octaves: 4,
+    ..NoiseParams::default()
+};
+
+let mut height_map = vec![0f32; 256 * 256];
+random_number::noise::noise_fill_2d(&mut height_map, 256, 256, &params);
+```
+
+### Stratified Sampling
+
+Uniform random values cluster badly for small sample counts. The `random_fill_stratified` function divides a range into equal-width strata and jitters one value in each of them, and the `latin_hypercube` function generates points whose every dimension has exactly one point in each stratum, which is handy for parameter sweeps.
+
+```rust
+let mut a = [0f64; 4];
+random_number::random_fill_stratified(&mut a, 0.0..1.0);
+
+println!("{:?}", a); // one value in each of [0, 0.25), [0.25, 0.5), [0.5, 0.75) and [0.75, 1)
+
+let points = random_number::latin_hypercube(8, 3, &[0.0..1.0, -5.0..5.0, 100.0..200.0]);
+
+for point in points {
+    println!("{:?}", point);
+}
+```
+
+### Quasi-random Sequences
+
+The `quasi` module provides the `Halton`, `Sobol` and `R2` low-discrepancy sequences, whose points cover the unit hypercube more evenly than random points, so Monte Carlo integrations converge faster. They can be scrambled with a random number generator. The `quasi_fill` and `quasi_fill_scrambled` functions spread integers or floats evenly over a range.
+
+```rust
+use random_number::quasi::Sobol;
+
+let mut rng = random_number::rand::thread_rng();
+
+// estimate π by the points in the quarter circle
+let n = 1 << 14;
+
+let inside = Sobol::new(2).scramble(&mut rng).take(n).filter(|p| p[0] * p[0] + p[1] * p[1] < 1.0).count();
+
+println!("{}", inside as f64 / n as f64 * 4.0);
+
+let mut a = [0f64; 8];
+random_number::quasi_fill(&mut a, 0.0..100.0);
+
+println!("{:?}", a); // [0.0, 50.0, 75.0, 25.0, 37.5, 87.5, 62.5, 12.5]
+```
+
+### Property Testing
+
+Enable the `proptest` feature to build proptest strategies with the range grammar of the `random!` macro, and the `quickcheck` feature for the `arbitrary!` macro and the `shrink_ranged` function in quickcheck `Arbitrary` implementations. Failing values shrink toward the lower bound of the range.
+
+```rust,ignore
+use proptest::prelude::*;
+use random_number::proptest::strategy;
+
+proptest! {
+    #[test]
+    fn percentage(n in strategy!(0..=100u8), m in strategy!(1u8, 10)) {
+        prop_assert!(n <= 100 && (1..=10).contains(&m));
+    }
+}
+```
+
+### Fuzzing
+
+Enable the `arbitrary` feature to drive the `_with_rng` functions by the input of a fuzzer. `fuzz::UnstructuredRng` is a random number generator which takes its bytes from an `arbitrary::Unstructured`, and `fuzz = u` takes the place of the random number generator in the `random!` and `random_fill!` macros.
+
+```rust,ignore
+use arbitrary::Unstructured;
+use random_number::{fuzz::UnstructuredRng, random};
+
+let mut u = Unstructured::new(data);
+
+let n: u8 = random!(1..=10, fuzz = u);
+
+let mut a = [0u16; 8];
+random_number::random_fill_inclusively_with_rng(&mut a, 1, 6, &mut UnstructuredRng::new(&mut u));
+```
+
+### Choosing the Default Random Number Generator
+
+The functions and macros without a random number generator argument use `rand::thread_rng()` by default. Enable one of the following features to use a faster generator instead, kept in a lazily-initialized thread-local variable and seeded by the operating system.
+
+| Feature       | Generator                               |
+|---------------|-----------------------------------------|
+| `rng-chacha`  | `rand_chacha::ChaCha8Rng`               |
+| `rng-pcg`     | `rand_pcg::Pcg64Mcg`                    |
+| `rng-xoshiro` | `rand_xoshiro::Xoshiro256PlusPlus`      |
+| `rng-wyrand`  | `wyhash::WyRng`                         |
+
+If several of them are enabled, the first one in the table is used. The selected generator can be named by the `DefaultRng` type and obtained by the `default_rng` function. Run `cargo bench` to compare their speed on your machine.
+
+```rust
+let mut rng: random_number::DefaultRng = random_number::default_rng();
+
+let n: u8 = random_number::random!(rng);
+println!("{}", n);
+```
 */
 pub extern crate rand;
 
+mod alias_table;
 mod bounded;
+mod default_rng;
+pub mod diagnostics;
+mod discrete;
+mod dynamic_weighted_sampler;
+#[cfg(feature = "arbitrary")]
+pub mod fuzz;
+pub mod generators;
+mod markov_chain;
+pub mod noise;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+pub mod quasi;
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck;
+mod random_bool_functions;
+mod random_bytes_functions;
+mod random_chunked_functions;
+mod random_components_functions;
+mod random_except_functions;
 mod random_fill_functions;
 mod random_functions;
-
+mod random_index_functions;
+mod random_net_functions;
+mod random_point_functions;
+mod random_sample_functions;
+mod random_series_functions;
+mod random_sorted_functions;
+mod random_stratified_functions;
+mod random_sum_functions;
+mod random_unique_functions;
+mod random_weighted_functions;
+mod reservoir;
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+mod shrinkable;
+mod weighted_table;
+
+pub use alias_table::{AliasTable, WeightError};
 pub use bounded::Bounded;
+pub use default_rng::{default_rng, DefaultRng, ThreadLocalRng};
+pub use discrete::Discrete;
+pub use dynamic_weighted_sampler::DynamicWeightedSampler;
+pub use markov_chain::{MarkovChain, ParseMarkovChainError};
 use proc_macro_hack::proc_macro_hack;
+pub use quasi::{quasi_fill, quasi_fill_scrambled};
+pub use random_bool_functions::*;
+pub use random_bytes_functions::*;
+pub use random_chunked_functions::*;
+pub use random_components_functions::*;
+pub use random_except_functions::*;
 pub use random_fill_functions::*;
 pub use random_functions::*;
+pub use random_index_functions::*;
+pub use random_net_functions::*;
+#[cfg(feature = "quickcheck")]
+#[doc(hidden)]
+#[proc_macro_hack]
+pub use random_number_macro_impl::arbitrary as __arbitrary;
 /**
 Generate a random number.
 
@@ -257,6 +782,64 @@ let i: i8 = random_number::random!(20, 12, thread_rng);
 
 assert!(12 <= i && i <= 20);
 ```
+
+```rust
+let (x, y): (u8, i16) = random_number::random!((0, -5)..=(10, 5));
+
+assert!(x <= 10 && -5 <= y && y <= 5);
+
+let a: [u8; 4] = random_number::random!([0; 4]..[10; 4]);
+
+for a in a.iter().copied() {
+    assert!(a < 10);
+}
+```
+
+```rust
+let i: u8 = random_number::random!(1..=10, except [3, 7]);
+
+assert!(1 <= i && i <= 10 && i != 3 && i != 7);
+
+let i: i8 = random_number::random!(..0, except [-100..=-2]);
+
+assert!(i < -100 || i == -1);
+
+// values and sub-ranges can be mixed
+let i: u8 = random_number::random!(1..=10, except [3, 5..7, 9..=10]);
+
+assert!(i == 1 || i == 2 || i == 4 || i == 7 || i == 8);
+```
+
+```rust
+use random_number::rand;
+
+let mut thread_rng = rand::thread_rng();
+
+let i: u16 = random_number::random!(1024.., except [8080, 8443], thread_rng);
+
+assert!(1024 <= i && i != 8080 && i != 8443);
+```
+
+```rust
+let i: u8 = random_number::random!(1..=6, weights = [1, 0, 1, 0, 1, 0]);
+
+assert!(i == 1 || i == 3 || i == 5);
+
+let w = 2.5;
+
+let i: i16 = random_number::random!(-1..2, weights = [w, 0.0, 1.0]);
+
+assert!(i == -1 || i == 1);
+```
+
+```rust
+use random_number::rand;
+
+let mut thread_rng = rand::thread_rng();
+
+assert!(random_number::random!(bool, p = 1.0));
+assert!(!random_number::random!(bool, p = 0.0, thread_rng));
+```
 */
 #[proc_macro_hack]
 pub use random_number_macro_impl::random;
@@ -488,6 +1071,77 @@ for i in i.iter().copied() {
     assert!(12 <= i && i <= 20);
 }
 ```
+
+```rust
+use random_number::rand;
+
+let mut thread_rng = rand::thread_rng();
+
+let mut p = [(0u8, 0.0f64); 100];
+random_number::random_fill!(p, (1, 0.5)..(10, 1.0), thread_rng);
+
+for (x, y) in p.iter().copied() {
+    assert!(1 <= x && x < 10 && 0.5 <= y && y < 1.0);
+}
+```
+
+```rust
+let mut i = [0u8; 100];
+random_number::random_fill!(i, 1..=10, except [3, 7..=8]);
+
+for i in i.iter().copied() {
+    assert!(1 <= i && i <= 10 && i != 3 && i != 7 && i != 8);
+}
+```
+
+```rust
+use random_number::rand;
+
+let mut thread_rng = rand::thread_rng();
+
+let mut i = [0i8; 100];
+random_number::random_fill!(i, -2..12, except [0..5], thread_rng);
+
+for i in i.iter().copied() {
+    assert!((-2 <= i && i < 0) || (5 <= i && i < 12));
+}
+```
+
+```rust
+use random_number::rand;
+
+let mut thread_rng = rand::thread_rng();
+
+let mut i = [0u32; 100];
+random_number::random_fill!(i, 10..=12, weights = [0, 3, 1], thread_rng);
+
+for i in i.iter().copied() {
+    assert!(i == 11 || i == 12);
+}
+```
+
+```rust
+let mut b = [false; 100];
+random_number::random_fill!(b, bool, p = 1.0);
+
+assert!(b.iter().all(|&b| b));
+```
 */
 #[proc_macro_hack]
 pub use random_number_macro_impl::random_fill;
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+#[proc_macro_hack]
+pub use random_number_macro_impl::strategy as __strategy;
+pub use random_point_functions::*;
+pub use random_sample_functions::*;
+pub use random_series_functions::*;
+pub use random_sorted_functions::*;
+pub use random_stratified_functions::*;
+pub use random_sum_functions::*;
+pub use random_unique_functions::*;
+pub use random_weighted_functions::*;
+pub use reservoir::Reservoir;
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+pub use shrinkable::Shrinkable;
+pub use weighted_table::{WeightedTable, WeightedTableIter};
@@ -0,0 +1,247 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    hash::Hash,
+    str::FromStr,
+};
+
+use crate::{default_rng, rand::Rng, WeightError, WeightedTable};
+
+/// Errors of parsing a `MarkovChain` from text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseMarkovChainError {
+    /// A line, counted from 1, is not in the form of `from to weight`.
+    InvalidLine(usize),
+    /// The weights cannot build a chain.
+    Weight(WeightError),
+}
+
+impl Display for ParseMarkovChainError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseMarkovChainError::InvalidLine(line) => {
+                write!(f, "line {} is not in the form of `from to weight`", line)
+            },
+            ParseMarkovChainError::Weight(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl Error for ParseMarkovChainError {}
+
+impl From<WeightError> for ParseMarkovChainError {
+    #[inline]
+    fn from(error: WeightError) -> Self {
+        ParseMarkovChainError::Weight(error)
+    }
+}
+
+/// A Markov chain over states of type `S`, which samples the next state in O(1) time.
+///
+/// A state without outgoing transitions is absorbing. The chain stops there.
+#[derive(Debug, Clone)]
+pub struct MarkovChain<S> {
+    states:      Vec<S>,
+    ids:         HashMap<S, usize>,
+    transitions: Vec<Option<WeightedTable<usize>>>,
+}
+
+impl<S: Eq + Hash + Clone> MarkovChain<S> {
+    /// Build a chain from `(from, to, weight)` triples. The probability of moving from a state to another is proportional to the weight of the transition.
+    pub fn new<I: IntoIterator<Item = (S, S, f64)>>(transitions: I) -> Result<Self, WeightError> {
+        let mut states = Vec::new();
+        let mut ids = HashMap::new();
+        let mut pairs: Vec<Vec<(usize, f64)>> = Vec::new();
+
+        for (from, to, weight) in transitions {
+            let mut id_of = |state: S| {
+                *ids.entry(state).or_insert_with_key(|state| {
+                    states.push(state.clone());
+                    pairs.push(Vec::new());
+
+                    states.len() - 1
+                })
+            };
+
+            let from = id_of(from);
+            let to = id_of(to);
+
+            pairs[from].push((to, weight));
+        }
+
+        let mut tables = Vec::with_capacity(pairs.len());
+
+        for pairs in pairs {
+            tables.push(if pairs.is_empty() { None } else { Some(WeightedTable::new(pairs)?) });
+        }
+
+        Ok(MarkovChain {
+            states,
+            ids,
+            transitions: tables,
+        })
+    }
+
+    /// All states, in the order they first appear in the transitions.
+    #[inline]
+    pub fn states(&self) -> &[S] {
+        &self.states
+    }
+
+    #[inline]
+    fn step_id_with_rng<T: Rng>(&self, from: usize, rng: &mut T) -> Option<usize> {
+        self.transitions[from].as_ref().map(|table| *table.sample_with_rng(rng))
+    }
+
+    /// Sample the state after `from` with a new lazily-initialized thread-local random number generator. Returns `None` if `from` is unknown or absorbing.
+    #[inline]
+    pub fn step(&self, from: &S) -> Option<&S> {
+        self.step_with_rng(from, &mut default_rng())
+    }
+
+    /// Sample the state after `from` with an existing random number generator. Returns `None` if `from` is unknown or absorbing.
+    #[inline]
+    pub fn step_with_rng<T: Rng>(&self, from: &S, rng: &mut T) -> Option<&S> {
+        let from = *self.ids.get(from)?;
+
+        self.step_id_with_rng(from, rng).map(|to| &self.states[to])
+    }
+
+    /// Generate a sequence of at most `n` states which begins with `start` with a new lazily-initialized thread-local random number generator. The sequence ends early at an absorbing state.
+    #[inline]
+    pub fn walk(&self, start: &S, n: usize) -> Vec<S> {
+        self.walk_with_rng(start, n, &mut default_rng())
+    }
+
+    /// Generate a sequence of at most `n` states which begins with `start` with an existing random number generator. The sequence ends early at an absorbing state.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use random_number::MarkovChain;
+    ///
+    /// let mut rng = random_number::rand::thread_rng();
+    ///
+    /// let chain = MarkovChain::new(vec![
+    ///     ("home", "search", 3.0),
+    ///     ("home", "cart", 1.0),
+    ///     ("search", "home", 1.0),
+    ///     ("search", "cart", 1.0),
+    ///     ("cart", "checkout", 1.0),
+    /// ])
+    /// .unwrap();
+    ///
+    /// let session = chain.walk_with_rng(&"home", 100, &mut rng);
+    ///
+    /// assert_eq!("home", session[0]);
+    /// assert_eq!(Some(&"checkout"), session.last());
+    /// ```
+    pub fn walk_with_rng<T: Rng>(&self, start: &S, n: usize, rng: &mut T) -> Vec<S> {
+        let mut sequence = Vec::with_capacity(n);
+
+        if n == 0 {
+            return sequence;
+        }
+
+        sequence.push(start.clone());
+
+        let mut current = match self.ids.get(start) {
+            Some(&id) => id,
+            None => return sequence,
+        };
+
+        while sequence.len() < n {
+            match self.step_id_with_rng(current, rng) {
+                Some(next) => {
+                    sequence.push(self.states[next].clone());
+
+                    current = next;
+                },
+                None => break,
+            }
+        }
+
+        sequence
+    }
+
+    /// Fill a slice with a sequence of states which begins with `start` with a new lazily-initialized thread-local random number generator. The rest of the slice stays at an absorbing state once it is reached.
+    #[inline]
+    pub fn fill(&self, out: &mut [S], start: &S) {
+        self.fill_with_rng(out, start, &mut default_rng())
+    }
+
+    /// Fill a slice with a sequence of states which begins with `start` with an existing random number generator. The rest of the slice stays at an absorbing state once it is reached.
+    pub fn fill_with_rng<T: Rng>(&self, out: &mut [S], start: &S, rng: &mut T) {
+        let mut current = self.ids.get(start).copied();
+
+        let mut iter = out.iter_mut();
+
+        if let Some(x) = iter.next() {
+            x.clone_from(start);
+        }
+
+        for x in iter {
+            match current.and_then(|id| self.step_id_with_rng(id, rng)) {
+                Some(next) => {
+                    x.clone_from(&self.states[next]);
+
+                    current = Some(next);
+                },
+                None => match current {
+                    Some(id) => x.clone_from(&self.states[id]),
+                    None => x.clone_from(start),
+                },
+            }
+        }
+    }
+}
+
+impl FromStr for MarkovChain<String> {
+    type Err = ParseMarkovChainError;
+
+    /// Parse transitions in the form of `from to weight`, one per line. Empty lines and lines starting with `#` are skipped.
+    ///
+    /// ```rust
+    /// use random_number::MarkovChain;
+    ///
+    /// let chain: MarkovChain<String> = "
+    ///     sunny sunny 0.8
+    ///     sunny rainy 0.2
+    ///     rainy sunny 0.4
+    ///     rainy rainy 0.6
+    /// "
+    /// .parse()
+    /// .unwrap();
+    ///
+    /// let weather = chain.walk(&"sunny".to_string(), 7);
+    ///
+    /// assert_eq!(7, weather.len());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut transitions = Vec::new();
+
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut tokens = line.split_whitespace();
+
+            match (tokens.next(), tokens.next(), tokens.next(), tokens.next()) {
+                (Some(from), Some(to), Some(weight), None) => {
+                    let weight =
+                        weight.parse().map_err(|_| ParseMarkovChainError::InvalidLine(i + 1))?;
+
+                    transitions.push((from.to_string(), to.to_string(), weight));
+                },
+                _ => return Err(ParseMarkovChainError::InvalidLine(i + 1)),
+            }
+        }
+
+        Ok(MarkovChain::new(transitions)?)
+    }
+}
@@ -0,0 +1,622 @@
+/*!
+Seeded coherent noise for procedural generation, such as terrains, textures and clouds.
+
+`ValueNoise`, `PerlinNoise` and `OpenSimplexNoise` implement the `Noise` trait, which samples 1D, 2D and 3D noise in [-1, 1]. The same seed always gives the same noise. `Fbm` adds octaves of a noise together (fractional Brownian motion), and `noise_fill_2d` fills a 2D map row by row.
+
+```rust
+use random_number::noise::{Fbm, Noise, PerlinNoise};
+
+let noise = Fbm::new(PerlinNoise::new(42)).octaves(5);
+
+let height = noise.sample_2d(12.5, 3.25);
+assert!((-1.0..=1.0).contains(&height));
+assert_eq!(height, Fbm::new(PerlinNoise::new(42)).octaves(5).sample_2d(12.5, 3.25));
+```
+*/
+
+use crate::{
+    generators::Xoshiro256StarStar,
+    rand::{seq::SliceRandom, Rng, SeedableRng},
+};
+
+/// A noise function which is continuous and whose outputs are in [-1, 1].
+pub trait Noise {
+    /// Sample the noise at a point of a line.
+    fn sample_1d(&self, x: f64) -> f64;
+
+    /// Sample the noise at a point of a plane.
+    fn sample_2d(&self, x: f64, y: f64) -> f64;
+
+    /// Sample the noise at a point of a space.
+    fn sample_3d(&self, x: f64, y: f64, z: f64) -> f64;
+}
+
+/// A shuffled permutation of 0 ~ 255 for hashing lattice points.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Permutation {
+    table: [u8; 256],
+}
+
+impl Permutation {
+    #[inline]
+    fn new<R: Rng>(rng: &mut R) -> Self {
+        let mut table = [0u8; 256];
+
+        for (i, x) in table.iter_mut().enumerate() {
+            *x = i as u8;
+        }
+
+        table.shuffle(rng);
+
+        Permutation {
+            table,
+        }
+    }
+
+    #[inline]
+    fn hash_1d(&self, i: i64) -> u8 {
+        self.table[(i & 255) as usize]
+    }
+
+    #[inline]
+    fn hash_2d(&self, i: i64, j: i64) -> u8 {
+        self.hash_1d(i64::from(self.hash_1d(i)).wrapping_add(j))
+    }
+
+    #[inline]
+    fn hash_3d(&self, i: i64, j: i64, k: i64) -> u8 {
+        self.hash_1d(i64::from(self.hash_2d(i, j)).wrapping_add(k))
+    }
+}
+
+/// The quintic smoothstep of Perlin's improved noise, whose first and second derivatives are zero at 0 and 1.
+#[inline]
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+#[inline]
+fn lerp(t: f64, a: f64, b: f64) -> f64 {
+    a + t * (b - a)
+}
+
+/// Split a coordinate into its lattice cell and its offset in the cell.
+#[inline]
+fn cell(x: f64) -> (i64, f64) {
+    let floor = x.floor();
+
+    (floor as i64, x - floor)
+}
+
+#[inline]
+fn gradient_1d(hash: u8, x: f64) -> f64 {
+    let g = f64::from(hash & 7) + 1.0;
+
+    if hash & 8 == 0 {
+        g * x
+    } else {
+        -g * x
+    }
+}
+
+#[inline]
+fn gradient_2d(hash: u8, x: f64, y: f64) -> f64 {
+    match hash & 7 {
+        0 => x + y,
+        1 => -x + y,
+        2 => x - y,
+        3 => -x - y,
+        4 => x,
+        5 => -x,
+        6 => y,
+        _ => -y,
+    }
+}
+
+#[inline]
+fn gradient_3d(hash: u8, x: f64, y: f64, z: f64) -> f64 {
+    // the 12 edges of a cube, with 4 of them repeated, as in Perlin's improved noise
+    let h = hash & 15;
+
+    let u = if h < 8 { x } else { y };
+    let v = if h < 4 {
+        y
+    } else if h == 12 || h == 14 {
+        x
+    } else {
+        z
+    };
+
+    (if h & 1 == 0 { u } else { -u }) + (if h & 2 == 0 { v } else { -v })
+}
+
+/// Value noise, which interpolates random values at the points of an integer lattice. It is the cheapest and the blockiest.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueNoise {
+    permutation: Permutation,
+    values:      [f64; 256],
+}
+
+impl ValueNoise {
+    /// Create the noise of a seed.
+    #[inline]
+    pub fn new(seed: u64) -> Self {
+        Self::from_rng(&mut Xoshiro256StarStar::seed_from_u64(seed))
+    }
+
+    /// Create a noise with an existing random number generator.
+    #[inline]
+    pub fn from_rng<R: Rng>(rng: &mut R) -> Self {
+        let permutation = Permutation::new(rng);
+
+        let mut values = [0f64; 256];
+
+        for x in values.iter_mut() {
+            *x = rng.gen_range(-1.0..=1.0);
+        }
+
+        ValueNoise {
+            permutation,
+            values,
+        }
+    }
+
+    #[inline]
+    fn value(&self, hash: u8) -> f64 {
+        self.values[usize::from(hash)]
+    }
+}
+
+impl Noise for ValueNoise {
+    fn sample_1d(&self, x: f64) -> f64 {
+        let (i, x) = cell(x);
+
+        let p = &self.permutation;
+
+        lerp(fade(x), self.value(p.hash_1d(i)), self.value(p.hash_1d(i.wrapping_add(1))))
+    }
+
+    fn sample_2d(&self, x: f64, y: f64) -> f64 {
+        let (i, x) = cell(x);
+        let (j, y) = cell(y);
+        let (i1, j1) = (i.wrapping_add(1), j.wrapping_add(1));
+
+        let p = &self.permutation;
+        let (u, v) = (fade(x), fade(y));
+
+        lerp(
+            v,
+            lerp(u, self.value(p.hash_2d(i, j)), self.value(p.hash_2d(i1, j))),
+            lerp(u, self.value(p.hash_2d(i, j1)), self.value(p.hash_2d(i1, j1))),
+        )
+    }
+
+    fn sample_3d(&self, x: f64, y: f64, z: f64) -> f64 {
+        let (i, x) = cell(x);
+        let (j, y) = cell(y);
+        let (k, z) = cell(z);
+        let (i1, j1, k1) = (i.wrapping_add(1), j.wrapping_add(1), k.wrapping_add(1));
+
+        let p = &self.permutation;
+        let (u, v, w) = (fade(x), fade(y), fade(z));
+
+        lerp(
+            w,
+            lerp(
+                v,
+                lerp(u, self.value(p.hash_3d(i, j, k)), self.value(p.hash_3d(i1, j, k))),
+                lerp(u, self.value(p.hash_3d(i, j1, k)), self.value(p.hash_3d(i1, j1, k))),
+            ),
+            lerp(
+                v,
+                lerp(u, self.value(p.hash_3d(i, j, k1)), self.value(p.hash_3d(i1, j, k1))),
+                lerp(u, self.value(p.hash_3d(i, j1, k1)), self.value(p.hash_3d(i1, j1, k1))),
+            ),
+        )
+    }
+}
+
+/// Perlin's improved gradient noise, which interpolates the dot products of random gradients at the points of an integer lattice. It is zero at every lattice point.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PerlinNoise {
+    permutation: Permutation,
+}
+
+impl PerlinNoise {
+    /// Create the noise of a seed.
+    #[inline]
+    pub fn new(seed: u64) -> Self {
+        Self::from_rng(&mut Xoshiro256StarStar::seed_from_u64(seed))
+    }
+
+    /// Create a noise with an existing random number generator.
+    #[inline]
+    pub fn from_rng<R: Rng>(rng: &mut R) -> Self {
+        PerlinNoise {
+            permutation: Permutation::new(rng)
+        }
+    }
+}
+
+impl Noise for PerlinNoise {
+    fn sample_1d(&self, x: f64) -> f64 {
+        let (i, x) = cell(x);
+
+        let p = &self.permutation;
+
+        let n = lerp(
+            fade(x),
+            gradient_1d(p.hash_1d(i), x),
+            gradient_1d(p.hash_1d(i.wrapping_add(1)), x - 1.0),
+        );
+
+        (n * PERLIN_SCALE_1D).clamp(-1.0, 1.0)
+    }
+
+    fn sample_2d(&self, x: f64, y: f64) -> f64 {
+        let (i, x) = cell(x);
+        let (j, y) = cell(y);
+        let (i1, j1) = (i.wrapping_add(1), j.wrapping_add(1));
+
+        let p = &self.permutation;
+        let (u, v) = (fade(x), fade(y));
+
+        let n = lerp(
+            v,
+            lerp(u, gradient_2d(p.hash_2d(i, j), x, y), gradient_2d(p.hash_2d(i1, j), x - 1.0, y)),
+            lerp(
+                u,
+                gradient_2d(p.hash_2d(i, j1), x, y - 1.0),
+                gradient_2d(p.hash_2d(i1, j1), x - 1.0, y - 1.0),
+            ),
+        );
+
+        (n * PERLIN_SCALE_2D).clamp(-1.0, 1.0)
+    }
+
+    fn sample_3d(&self, x: f64, y: f64, z: f64) -> f64 {
+        let (i, x) = cell(x);
+        let (j, y) = cell(y);
+        let (k, z) = cell(z);
+        let (i1, j1, k1) = (i.wrapping_add(1), j.wrapping_add(1), k.wrapping_add(1));
+        let (x1, y1, z1) = (x - 1.0, y - 1.0, z - 1.0);
+
+        let p = &self.permutation;
+        let (u, v, w) = (fade(x), fade(y), fade(z));
+
+        let n = lerp(
+            w,
+            lerp(
+                v,
+                lerp(
+                    u,
+                    gradient_3d(p.hash_3d(i, j, k), x, y, z),
+                    gradient_3d(p.hash_3d(i1, j, k), x1, y, z),
+                ),
+                lerp(
+                    u,
+                    gradient_3d(p.hash_3d(i, j1, k), x, y1, z),
+                    gradient_3d(p.hash_3d(i1, j1, k), x1, y1, z),
+                ),
+            ),
+            lerp(
+                v,
+                lerp(
+                    u,
+                    gradient_3d(p.hash_3d(i, j, k1), x, y, z1),
+                    gradient_3d(p.hash_3d(i1, j, k1), x1, y, z1),
+                ),
+                lerp(
+                    u,
+                    gradient_3d(p.hash_3d(i, j1, k1), x, y1, z1),
+                    gradient_3d(p.hash_3d(i1, j1, k1), x1, y1, z1),
+                ),
+            ),
+        );
+
+        (n * PERLIN_SCALE_3D).clamp(-1.0, 1.0)
+    }
+}
+
+// the scales map the measured extremes of the raw noises to about ±1, and the outputs are clamped in case a rarer extreme exceeds them
+const PERLIN_SCALE_1D: f64 = 0.25;
+const PERLIN_SCALE_2D: f64 = 1.0;
+const PERLIN_SCALE_3D: f64 = 0.97;
+
+/// Gradient noise on the lattices of OpenSimplex2 by K.jpg: the triangular lattice in 2D and the body-centered cubic lattice in 3D. Every point only sums the radial kernels of the nearby lattice points, so it has fewer directional artifacts than Perlin noise. The 1D noise is the 2D noise along the x-axis.
+///
+/// The gradients and the hashing differ from the reference implementation, so the outputs are not the same.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenSimplexNoise {
+    permutation: Permutation,
+}
+
+impl OpenSimplexNoise {
+    /// Create the noise of a seed.
+    #[inline]
+    pub fn new(seed: u64) -> Self {
+        Self::from_rng(&mut Xoshiro256StarStar::seed_from_u64(seed))
+    }
+
+    /// Create a noise with an existing random number generator.
+    #[inline]
+    pub fn from_rng<R: Rng>(rng: &mut R) -> Self {
+        OpenSimplexNoise {
+            permutation: Permutation::new(rng)
+        }
+    }
+}
+
+/// (√3 - 1) / 2, which skews the triangular lattice into the square lattice.
+const SKEW_2D: f64 = 0.366_025_403_784_438_6;
+/// (3 - √3) / 6, which unskews the square lattice back into the triangular lattice.
+const UNSKEW_2D: f64 = 0.211_324_865_405_187_1;
+
+const OPEN_SIMPLEX_SCALE_2D: f64 = 70.0;
+const OPEN_SIMPLEX_SCALE_3D: f64 = 32.5;
+
+impl Noise for OpenSimplexNoise {
+    #[inline]
+    fn sample_1d(&self, x: f64) -> f64 {
+        self.sample_2d(x, 0.0)
+    }
+
+    fn sample_2d(&self, x: f64, y: f64) -> f64 {
+        let s = (x + y) * SKEW_2D;
+
+        let (i, _) = cell(x + s);
+        let (j, _) = cell(y + s);
+
+        let mut n = 0.0;
+
+        // the kernels have a squared radius of 1/2, so only the corners of the skewed cell containing the point can reach it
+        for (di, dj) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+            let (pi, pj) = (i.wrapping_add(di), j.wrapping_add(dj));
+
+            let t = (pi as f64 + pj as f64) * UNSKEW_2D;
+            let dx = x - (pi as f64 - t);
+            let dy = y - (pj as f64 - t);
+
+            let a = 0.5 - dx * dx - dy * dy;
+
+            if a > 0.0 {
+                let a2 = a * a;
+
+                n += a2 * a2 * gradient_2d(self.permutation.hash_2d(pi, pj), dx, dy);
+            }
+        }
+
+        (n * OPEN_SIMPLEX_SCALE_2D).clamp(-1.0, 1.0)
+    }
+
+    fn sample_3d(&self, x: f64, y: f64, z: f64) -> f64 {
+        // a reflection which turns the cubic axes of the lattice away from the axes of the input
+        let r = (x + y + z) * (2.0 / 3.0);
+        let (x, y, z) = (r - x, r - y, r - z);
+
+        let mut n = 0.0;
+
+        // the body-centered cubic lattice is two cubic lattices, one of them shifted by a half; the kernels have a squared radius of 0.6, so only the corners of the cube containing the point in each of them can reach it
+        for parity in 0..2 {
+            let shift = f64::from(parity) * 0.5;
+
+            let (i, _) = cell(x - shift);
+            let (j, _) = cell(y - shift);
+            let (k, _) = cell(z - shift);
+
+            for corner in 0..8 {
+                let pi = i.wrapping_add(corner & 1);
+                let pj = j.wrapping_add((corner >> 1) & 1);
+                let pk = k.wrapping_add(corner >> 2);
+
+                let dx = x - (pi as f64 + shift);
+                let dy = y - (pj as f64 + shift);
+                let dz = z - (pk as f64 + shift);
+
+                let a = 0.6 - dx * dx - dy * dy - dz * dz;
+
+                if a > 0.0 {
+                    let a2 = a * a;
+
+                    // the points of the two cubic lattices are the even and the odd points of a doubled lattice
+                    let hash = self.permutation.hash_3d(
+                        pi.wrapping_mul(2).wrapping_add(i64::from(parity)),
+                        pj.wrapping_mul(2).wrapping_add(i64::from(parity)),
+                        pk.wrapping_mul(2).wrapping_add(i64::from(parity)),
+                    );
+
+                    n += a2 * a2 * gradient_3d(hash, dx, dy, dz);
+                }
+            }
+        }
+
+        (n * OPEN_SIMPLEX_SCALE_3D).clamp(-1.0, 1.0)
+    }
+}
+
+/// Fractional Brownian motion, which adds octaves of a noise together. Each octave has `lacunarity` times the frequency and `persistence` times the amplitude of the previous one, and the sum is normalized back into [-1, 1].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fbm<N> {
+    noise:       N,
+    octaves:     u32,
+    lacunarity:  f64,
+    persistence: f64,
+}
+
+impl<N: Noise> Fbm<N> {
+    /// Wrap a noise with 4 octaves, a lacunarity of 2 and a persistence of 0.5.
+    #[inline]
+    pub fn new(noise: N) -> Self {
+        Fbm {
+            noise,
+            octaves: 4,
+            lacunarity: 2.0,
+            persistence: 0.5,
+        }
+    }
+
+    /// Set the number of octaves. One octave is the noise itself.
+    ///
+    /// Panics if `octaves` is zero.
+    #[inline]
+    pub fn octaves(mut self, octaves: u32) -> Self {
+        if octaves == 0 {
+            panic!("Fbm::octaves called with zero octaves");
+        }
+
+        self.octaves = octaves;
+
+        self
+    }
+
+    /// Set the frequency multiplier between octaves.
+    #[inline]
+    pub fn lacunarity(mut self, lacunarity: f64) -> Self {
+        self.lacunarity = lacunarity;
+
+        self
+    }
+
+    /// Set the amplitude multiplier between octaves.
+    #[inline]
+    pub fn persistence(mut self, persistence: f64) -> Self {
+        self.persistence = persistence;
+
+        self
+    }
+
+    /// Sum the octaves of `sample`, which is called with the frequency and the shift of each octave.
+    #[inline]
+    fn sum(&self, mut sample: impl FnMut(f64, f64) -> f64) -> f64 {
+        let mut frequency = 1.0;
+        let mut amplitude = 1.0;
+
+        let mut n = 0.0;
+        let mut total_amplitude = 0.0;
+
+        for octave in 0..self.octaves {
+            // shift the octaves apart, or all of them would meet the lattice at the origin together
+            n += amplitude * sample(frequency, f64::from(octave) * OCTAVE_SHIFT);
+            total_amplitude += amplitude;
+
+            frequency *= self.lacunarity;
+            amplitude *= self.persistence;
+        }
+
+        n / total_amplitude
+    }
+}
+
+const OCTAVE_SHIFT: f64 = 19.19;
+
+impl<N: Noise> Noise for Fbm<N> {
+    #[inline]
+    fn sample_1d(&self, x: f64) -> f64 {
+        self.sum(|f, shift| self.noise.sample_1d(x * f + shift))
+    }
+
+    #[inline]
+    fn sample_2d(&self, x: f64, y: f64) -> f64 {
+        self.sum(|f, shift| self.noise.sample_2d(x * f + shift, y * f + shift))
+    }
+
+    #[inline]
+    fn sample_3d(&self, x: f64, y: f64, z: f64) -> f64 {
+        self.sum(|f, shift| self.noise.sample_3d(x * f + shift, y * f + shift, z * f + shift))
+    }
+}
+
+/// The kinds of noise of `NoiseParams`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NoiseKind {
+    /// `ValueNoise`.
+    Value,
+    /// `PerlinNoise`.
+    Perlin,
+    /// `OpenSimplexNoise`.
+    OpenSimplex,
+}
+
+/// The parameters of `noise_fill_2d`. The default is 1 octave of Perlin noise with the seed 0 and a frequency of 1/32.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoiseParams {
+    /// The kind of noise.
+    pub kind:        NoiseKind,
+    /// The seed of the noise.
+    pub seed:        u64,
+    /// The number of lattice cells per pixel of the first octave, so its features are about `1 / frequency` pixels wide.
+    pub frequency:   f64,
+    /// The number of octaves, which is at least 1.
+    pub octaves:     u32,
+    /// The frequency multiplier between octaves.
+    pub lacunarity:  f64,
+    /// The amplitude multiplier between octaves.
+    pub persistence: f64,
+}
+
+impl Default for NoiseParams {
+    #[inline]
+    fn default() -> Self {
+        NoiseParams {
+            kind:        NoiseKind::Perlin,
+            seed:        0,
+            frequency:   1.0 / 32.0,
+            octaves:     1,
+            lacunarity:  2.0,
+            persistence: 0.5,
+        }
+    }
+}
+
+/// Fill a `width` × `height` map of noise in row-major order, so the pixel (`x`, `y`) is `out[y * width + x]` and is sampled at (`x * frequency`, `y * frequency`).
+///
+/// Panics if the length of `out` is not `width * height` or `params.octaves` is zero.
+///
+/// ## Examples
+///
+/// ```rust
+/// use random_number::noise::{NoiseKind, NoiseParams};
+///
+/// let params = NoiseParams {
+///     kind: NoiseKind::OpenSimplex,
+///     seed: 42,
+///     octaves: 4,
+///     ..NoiseParams::default()
+/// };
+///
+/// let mut height_map = vec![0f32; 64 * 48];
+/// random_number::noise::noise_fill_2d(&mut height_map, 64, 48, &params);
+///
+/// assert!(height_map.iter().all(|h| (-1.0..=1.0).contains(h)));
+/// ```
+pub fn noise_fill_2d(out: &mut [f32], width: usize, height: usize, params: &NoiseParams) {
+    if width.checked_mul(height) != Some(out.len()) {
+        panic!("noise_fill_2d called with a slice whose length is not width * height");
+    }
+
+    match params.kind {
+        NoiseKind::Value => fill_2d(out, width, ValueNoise::new(params.seed), params),
+        NoiseKind::Perlin => fill_2d(out, width, PerlinNoise::new(params.seed), params),
+        NoiseKind::OpenSimplex => fill_2d(out, width, OpenSimplexNoise::new(params.seed), params),
+    }
+}
+
+#[inline]
+fn fill_2d<N: Noise>(out: &mut [f32], width: usize, noise: N, params: &NoiseParams) {
+    let fbm = Fbm::new(noise)
+        .octaves(params.octaves)
+        .lacunarity(params.lacunarity)
+        .persistence(params.persistence);
+
+    if width == 0 {
+        return;
+    }
+
+    for (y, row) in out.chunks_exact_mut(width).enumerate() {
+        let y = y as f64 * params.frequency;
+
+        for (x, h) in row.iter_mut().enumerate() {
+            *h = fbm.sample_2d(x as f64 * params.frequency, y) as f32;
+        }
+    }
+}
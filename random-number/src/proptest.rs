@@ -0,0 +1,171 @@
+/*!
+Strategies for [proptest](https://crates.io/crates/proptest) which generate values with the same range grammar as the `random!` macro. This module is available with the `proptest` feature.
+
+A failing value shrinks toward the lower bound of its range by bisection, so the reported case is the smallest failing value in the range.
+
+```rust
+use proptest::{
+    prop_assert,
+    test_runner::{TestError, TestRunner},
+};
+use random_number::proptest::strategy;
+
+let mut runner = TestRunner::default();
+
+let result = runner.run(&strategy!(10..=1000u16), |n| {
+    prop_assert!(n < 100);
+
+    Ok(())
+});
+
+match result {
+    Err(TestError::Fail(_, n)) => assert_eq!(100, n),
+    _ => unreachable!(),
+}
+```
+*/
+
+use std::{
+    fmt::Debug,
+    ops::{Bound, RangeBounds},
+};
+
+use ::proptest::{
+    arbitrary::any,
+    strategy::{NewTree, Strategy, ValueTree},
+    test_runner::TestRunner,
+};
+
+use crate::{
+    generators::Xoshiro256StarStar,
+    rand::{distributions::uniform::SampleUniform, SeedableRng},
+    random_ranged_with_rng, Bounded, Shrinkable,
+};
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __proptest_strategy {
+    ($($args:tt)*) => {
+        $crate::__strategy!($($args)*)
+    };
+}
+
+/// Create a `RangeStrategy` from the arguments of the `random!` macro without the random number generator.
+///
+/// ```rust
+/// use random_number::proptest::strategy;
+///
+/// let _ = strategy!(1..=10u8);
+/// let _ = strategy!(..100i32);
+/// let _ = strategy!(20u64, 12);
+/// let _ = strategy!(-1.0..1.0f64);
+/// let _: random_number::proptest::RangeStrategy<u8> = strategy!();
+/// ```
+#[doc(inline)]
+pub use crate::__proptest_strategy as strategy;
+
+/// A strategy which generates values in a range and shrinks them toward the start of the range.
+#[derive(Debug, Clone, Copy)]
+pub struct RangeStrategy<X> {
+    low: X,
+    end: Bound<X>,
+}
+
+impl<X: Shrinkable + Bounded> RangeStrategy<X> {
+    /// Create a strategy for a range. If the start bound is unbounded, the range starts at `X::min_value()`.
+    ///
+    /// Panics if the start bound is exclusive.
+    #[inline]
+    pub fn new<R: RangeBounds<X>>(range: R) -> RangeStrategy<X> {
+        let low = match range.start_bound() {
+            Bound::Included(&low) => low,
+            Bound::Excluded(_) => {
+                panic!("RangeStrategy::new called with a start bound which is exclusive")
+            },
+            Bound::Unbounded => X::min_value(),
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(&high) => Bound::Included(high),
+            Bound::Excluded(&high) => Bound::Excluded(high),
+            Bound::Unbounded => Bound::Unbounded,
+        };
+
+        RangeStrategy {
+            low,
+            end,
+        }
+    }
+}
+
+impl<X: Shrinkable + Bounded + SampleUniform + Debug> Strategy for RangeStrategy<X> {
+    type Tree = RangeValueTree<X>;
+    type Value = X;
+
+    #[inline]
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        let seed = any::<u64>().new_tree(runner)?.current();
+
+        let mut rng = Xoshiro256StarStar::seed_from_u64(seed);
+
+        let value = random_ranged_with_rng((Bound::Included(self.low), self.end), &mut rng);
+
+        Ok(RangeValueTree {
+            low: self.low, current: value, high: value
+        })
+    }
+}
+
+/// A value generated by a `RangeStrategy`, which is shrunk by a binary search between the start of the range and the smallest failing value found so far.
+#[derive(Debug, Clone, Copy)]
+pub struct RangeValueTree<X> {
+    low:     X,
+    current: X,
+    high:    X,
+}
+
+impl<X: Shrinkable> RangeValueTree<X> {
+    #[inline]
+    fn reposition(&mut self) -> bool {
+        let midpoint = X::midpoint(self.low, self.high);
+
+        if midpoint == self.current {
+            false
+        } else {
+            self.current = midpoint;
+
+            true
+        }
+    }
+}
+
+impl<X: Shrinkable + Debug> ValueTree for RangeValueTree<X> {
+    type Value = X;
+
+    #[inline]
+    fn current(&self) -> X {
+        self.current
+    }
+
+    #[inline]
+    fn simplify(&mut self) -> bool {
+        if self.low >= self.high {
+            return false;
+        }
+
+        self.high = self.current;
+
+        self.reposition()
+    }
+
+    #[inline]
+    fn complicate(&mut self) -> bool {
+        if self.current >= self.high {
+            return false;
+        }
+
+        self.low = self.current.successor();
+
+        self.reposition()
+    }
+}
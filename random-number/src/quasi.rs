@@ -0,0 +1,644 @@
+/*!
+Quasi-random (low-discrepancy) sequences, whose points cover the unit hypercube more evenly than random points, so Monte Carlo integrations converge faster.
+
+`Halton`, `Sobol` and `R2` are iterators of points in [0, 1)<sup>d</sup>, which also compute any point by its index. Their `scramble` methods randomize a sequence with a random number generator while keeping its low discrepancy, so that independent replications can estimate the error of an integration. `quasi_fill` maps a one-dimensional sequence into a range.
+
+```rust
+use random_number::quasi::Sobol;
+
+// estimate the integral of x * y over the unit square, which is 1/4
+let n = 1 << 12;
+
+let sum: f64 = Sobol::new(2).take(n).map(|p| p[0] * p[1]).sum();
+
+assert!((sum / n as f64 - 0.25).abs() < 1e-3);
+```
+*/
+
+mod joe_kuo;
+
+use std::{
+    iter::FusedIterator,
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{generators::mix64, rand::Rng, Bounded};
+
+/// The largest `f64` which is less than 1.
+const ONE_MINUS_EPSILON: f64 = 1.0 - f64::EPSILON / 2.0;
+
+/// Derive the scrambling seed of a dimension.
+#[inline]
+fn dimension_seed(seed: u64, dimension: usize) -> u64 {
+    mix64(seed ^ mix64(dimension as u64))
+}
+
+/// Find the first `n` prime numbers.
+fn first_primes(n: usize) -> Vec<u32> {
+    let mut primes: Vec<u32> = Vec::with_capacity(n);
+
+    let mut candidate = 2;
+
+    while primes.len() < n {
+        if primes.iter().take_while(|&&p| p * p <= candidate).all(|&p| candidate % p != 0) {
+            primes.push(candidate);
+        }
+
+        candidate += 1;
+    }
+
+    primes
+}
+
+/// The Halton sequence, whose dimension `i` is the radical inverse of the index in the `i`-th prime base. It has no limit on the number of dimensions, but the dimensions with large bases are correlated unless the sequence is scrambled.
+///
+/// ```rust
+/// use random_number::quasi::Halton;
+///
+/// let points: Vec<Vec<f64>> = Halton::new(2).take(4).collect();
+///
+/// assert_eq!(
+///     vec![
+///         vec![0.0, 0.0],
+///         vec![0.5, 1.0 / 3.0],
+///         vec![0.25, 2.0 / 3.0],
+///         vec![0.75, 1.0 / 9.0]
+///     ],
+///     points
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Halton {
+    bases: Vec<u32>,
+    seed:  Option<u64>,
+    index: u64,
+}
+
+impl Halton {
+    /// Create a Halton sequence of `dims` dimensions, starting from the origin.
+    ///
+    /// Panics if `dims` is zero.
+    #[inline]
+    pub fn new(dims: usize) -> Self {
+        if dims == 0 {
+            panic!("Halton::new called with zero dimensions");
+        }
+
+        Halton {
+            bases: first_primes(dims), seed: None, index: 0
+        }
+    }
+
+    /// The number of dimensions.
+    #[inline]
+    pub fn dims(&self) -> usize {
+        self.bases.len()
+    }
+
+    /// Scramble the sequence with a seed drawn from `rng`. Every digit of a coordinate goes through a random affine permutation which depends on the digits before it, a nested variant of Owen scrambling which is exactly Owen scrambling in base 2.
+    #[inline]
+    pub fn scramble<R: Rng>(mut self, rng: &mut R) -> Self {
+        self.seed = Some(rng.gen());
+
+        self
+    }
+
+    /// Compute the point at `index`.
+    pub fn point(&self, index: u64) -> Vec<f64> {
+        self.bases
+            .iter()
+            .enumerate()
+            .map(|(dimension, &base)| match self.seed {
+                Some(seed) => {
+                    scrambled_radical_inverse(base, index, dimension_seed(seed, dimension))
+                },
+                None => radical_inverse(base, index),
+            })
+            .collect()
+    }
+}
+
+#[inline]
+fn radical_inverse(base: u32, mut index: u64) -> f64 {
+    let base = u64::from(base);
+    let inverse = 1.0 / base as f64;
+
+    let mut f = inverse;
+    let mut result = 0.0;
+
+    while index > 0 {
+        result += (index % base) as f64 * f;
+
+        index /= base;
+        f *= inverse;
+    }
+
+    result
+}
+
+fn scrambled_radical_inverse(base: u32, mut index: u64, mut hash: u64) -> f64 {
+    let base = u64::from(base);
+    let inverse = 1.0 / base as f64;
+
+    let mut f = inverse;
+    let mut result = 0.0;
+
+    // the zeros after the digits of the index are scrambled too, until the digits are below the precision of `f64`
+    while f >= f64::EPSILON / 4.0 {
+        let digit = index % base;
+
+        let a = 1 + hash % (base - 1);
+        let c = (hash >> 32) % base;
+
+        result += ((a * digit + c) % base) as f64 * f;
+
+        index /= base;
+        f *= inverse;
+        hash = mix64(hash.wrapping_add(digit + 1));
+    }
+
+    result.min(ONE_MINUS_EPSILON)
+}
+
+impl Iterator for Halton {
+    type Item = Vec<f64>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.point(self.index);
+
+        self.index = self.index.wrapping_add(1);
+
+        Some(point)
+    }
+}
+
+impl FusedIterator for Halton {}
+
+/// The Sobol sequence with the direction numbers `new-joe-kuo-6.1000` of Stephen Joe and Frances Y. Kuo, for up to 1000 dimensions and 2<sup>32</sup> points. The first 2<sup>m</sup> points are perfectly stratified in each dimension.
+///
+/// ```rust
+/// use random_number::quasi::Sobol;
+///
+/// let points: Vec<Vec<f64>> = Sobol::new(3).take(4).collect();
+///
+/// assert_eq!(
+///     vec![
+///         vec![0.0, 0.0, 0.0],
+///         vec![0.5, 0.5, 0.5],
+///         vec![0.75, 0.25, 0.25],
+///         vec![0.25, 0.75, 0.75]
+///     ],
+///     points
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sobol {
+    directions: Vec<[u32; 32]>,
+    seeds:      Option<Vec<u32>>,
+    x:          Vec<u32>,
+    index:      u64,
+}
+
+impl Sobol {
+    /// The maximum number of dimensions.
+    pub const MAX_DIMENSIONS: usize = 1000;
+
+    /// Create a Sobol sequence of `dims` dimensions, starting from the origin.
+    ///
+    /// Panics if `dims` is zero or greater than `Sobol::MAX_DIMENSIONS`.
+    pub fn new(dims: usize) -> Self {
+        if dims == 0 || dims > Self::MAX_DIMENSIONS {
+            panic!(
+                "Sobol::new called with a number of dimensions out of [1, {}]",
+                Self::MAX_DIMENSIONS
+            );
+        }
+
+        let mut directions = Vec::with_capacity(dims);
+
+        let mut v = [0u32; 32];
+
+        for (k, v) in v.iter_mut().enumerate() {
+            *v = 1 << (31 - k);
+        }
+
+        directions.push(v);
+
+        for &(s, a, m) in joe_kuo::DIRECTION_NUMBERS.iter().take(dims - 1) {
+            let s = s as usize;
+
+            let mut v = [0u32; 32];
+
+            for k in 0..32 {
+                v[k] = if k < s {
+                    m[k] << (31 - k)
+                } else {
+                    let mut v_k = v[k - s] ^ (v[k - s] >> s);
+
+                    for l in 1..s {
+                        if (a >> (s - 1 - l)) & 1 == 1 {
+                            v_k ^= v[k - l];
+                        }
+                    }
+
+                    v_k
+                };
+            }
+
+            directions.push(v);
+        }
+
+        Sobol {
+            directions,
+            seeds: None,
+            x: vec![0; dims],
+            index: 0,
+        }
+    }
+
+    /// The number of dimensions.
+    #[inline]
+    pub fn dims(&self) -> usize {
+        self.directions.len()
+    }
+
+    /// Scramble the sequence with a seed drawn from `rng`, by the hash-based Owen scrambling of Brent Burley. The stratification of the sequence is kept.
+    #[inline]
+    pub fn scramble<R: Rng>(mut self, rng: &mut R) -> Self {
+        let seed: u64 = rng.gen();
+
+        self.seeds = Some(
+            (0..self.dims()).map(|dimension| dimension_seed(seed, dimension) as u32).collect(),
+        );
+
+        self
+    }
+
+    /// Compute the point at `index`.
+    ///
+    /// Panics if `index` is not less than 2<sup>32</sup>.
+    pub fn point(&self, index: u64) -> Vec<f64> {
+        if index >> 32 != 0 {
+            panic!("Sobol::point called with an index which is not less than 2^32");
+        }
+
+        let gray = index ^ (index >> 1);
+
+        let x: Vec<u32> = self
+            .directions
+            .iter()
+            .map(|v| {
+                v.iter()
+                    .enumerate()
+                    .filter(|&(k, _)| (gray >> k) & 1 == 1)
+                    .fold(0, |x, (_, v)| x ^ v)
+            })
+            .collect();
+
+        self.to_point(&x)
+    }
+
+    #[inline]
+    fn to_point(&self, x: &[u32]) -> Vec<f64> {
+        match &self.seeds {
+            Some(seeds) => {
+                x.iter().zip(seeds).map(|(&x, &seed)| to_unit(owen_scramble(x, seed))).collect()
+            },
+            None => x.iter().map(|&x| to_unit(x)).collect(),
+        }
+    }
+}
+
+#[inline]
+fn to_unit(x: u32) -> f64 {
+    f64::from(x) / (1u64 << 32) as f64
+}
+
+/// The nested uniform scrambling of the bits of `x` by a hash, from "Practical Hash-based Owen Scrambling" by Brent Burley.
+#[inline]
+fn owen_scramble(x: u32, seed: u32) -> u32 {
+    let mut x = x.reverse_bits();
+
+    x ^= x.wrapping_mul(0x3D20ADEA);
+    x = x.wrapping_add(seed);
+    x = x.wrapping_mul((seed >> 16) | 1);
+    x ^= x.wrapping_mul(0x05526C56);
+    x ^= x.wrapping_mul(0x53A22864);
+
+    x.reverse_bits()
+}
+
+impl Iterator for Sobol {
+    type Item = Vec<f64>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >> 32 != 0 {
+            return None;
+        }
+
+        if self.index > 0 {
+            // the Gray code order changes one bit at a time, the lowest zero bit of the previous index
+            let c = (self.index - 1).trailing_ones() as usize;
+
+            for (x, v) in self.x.iter_mut().zip(self.directions.iter()) {
+                *x ^= v[c];
+            }
+        }
+
+        self.index += 1;
+
+        Some(self.to_point(&self.x))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (1u64 << 32) - self.index;
+
+        (usize::try_from(remaining).unwrap_or(usize::MAX), usize::try_from(remaining).ok())
+    }
+}
+
+impl FusedIterator for Sobol {}
+
+/// The R2 sequence of Martin Roberts, generalized to any number of dimensions. The coordinate `i` of the point `n` is the fractional part of `0.5 + n * α_i`, where `α_i` is the (`i` + 1)-th power of 1 / φ<sub>d</sub> and φ<sub>d</sub> is the only positive root of x<sup>d + 1</sup> = x + 1. It is the cheapest of the three, and its one-dimensional case is the golden ratio sequence.
+///
+/// ```rust
+/// use random_number::quasi::R2;
+///
+/// let point = R2::new(2).nth(1).unwrap();
+///
+/// assert!((point[0] - 0.2548776662466927).abs() < 1e-12);
+/// assert!((point[1] - 0.0698402909980532).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct R2 {
+    alphas: Vec<u64>,
+    shifts: Vec<u64>,
+    index:  u64,
+}
+
+impl R2 {
+    /// Create an R2 sequence of `dims` dimensions, starting from the center of the unit hypercube.
+    ///
+    /// Panics if `dims` is zero.
+    pub fn new(dims: usize) -> Self {
+        if dims == 0 {
+            panic!("R2::new called with zero dimensions");
+        }
+
+        let mut phi = 2.0f64;
+
+        for _ in 0..64 {
+            phi = (1.0 + phi).powf(1.0 / (dims as f64 + 1.0));
+        }
+
+        // the coordinates are 64-bit fixed-point numbers, so adding `α` wraps around exactly
+        let alphas = (1..=dims)
+            .map(|i| {
+                let alpha = phi.powi(-(i as i32));
+
+                (alpha.fract() * (1u128 << 64) as f64) as u64
+            })
+            .collect();
+
+        R2 {
+            alphas,
+            shifts: vec![1 << 63; dims],
+            index: 0,
+        }
+    }
+
+    /// The number of dimensions.
+    #[inline]
+    pub fn dims(&self) -> usize {
+        self.alphas.len()
+    }
+
+    /// Randomize the sequence by shifting every dimension by a random offset drawn from `rng` and wrapping around (a Cranley-Patterson rotation), because the R2 sequence has no digits to be scrambled.
+    #[inline]
+    pub fn scramble<R: Rng>(mut self, rng: &mut R) -> Self {
+        for shift in self.shifts.iter_mut() {
+            *shift = rng.gen();
+        }
+
+        self
+    }
+
+    /// Compute the point at `index`.
+    #[inline]
+    pub fn point(&self, index: u64) -> Vec<f64> {
+        self.alphas
+            .iter()
+            .zip(self.shifts.iter())
+            .map(|(&alpha, &shift)| {
+                let x = shift.wrapping_add(index.wrapping_mul(alpha));
+
+                (x >> 11) as f64 * (f64::EPSILON / 2.0)
+            })
+            .collect()
+    }
+}
+
+impl Iterator for R2 {
+    type Item = Vec<f64>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.point(self.index);
+
+        self.index = self.index.wrapping_add(1);
+
+        Some(point)
+    }
+}
+
+impl FusedIterator for R2 {}
+
+/// Types which the points of quasi-random sequences can be mapped into.
+pub trait UnitMappable: Bounded + Copy + PartialOrd {
+    /// Map `u` in [0, 1) linearly onto [`min`, `max`], or onto [`min`, `max`) if `exclusive` is true, in spite of rounding. Integers take the floor, so each of them is reached by an equal share of [0, 1).
+    fn unit_map(min: Self, max: Self, exclusive: bool, u: f64) -> Self;
+}
+
+macro_rules! unit_mappable_integer_impl {
+    ($t:ident, $u:ident) => {
+        impl UnitMappable for $t {
+            #[inline]
+            fn unit_map(min: $t, max: $t, exclusive: bool, u: f64) -> $t {
+                let span = (max as $u).wrapping_sub(min as $u) as u128;
+
+                // the number of values in the range, which is 0 for all the values of `u128`
+                let count = if exclusive { span } else { span.wrapping_add(1) };
+
+                // `u` as a fixed-point fraction of 64 bits
+                let fraction = (u * 18446744073709551616.0) as u64 as u128;
+
+                let offset = if count == 0 {
+                    fraction << 64
+                } else {
+                    (count >> 64) * fraction + ((count as u64 as u128 * fraction) >> 64)
+                };
+
+                (min as $u).wrapping_add(offset as $u) as $t
+            }
+        }
+    };
+}
+
+unit_mappable_integer_impl!(u8, u8);
+unit_mappable_integer_impl!(u16, u16);
+unit_mappable_integer_impl!(u32, u32);
+unit_mappable_integer_impl!(u64, u64);
+unit_mappable_integer_impl!(u128, u128);
+unit_mappable_integer_impl!(usize, usize);
+unit_mappable_integer_impl!(i8, u8);
+unit_mappable_integer_impl!(i16, u16);
+unit_mappable_integer_impl!(i32, u32);
+unit_mappable_integer_impl!(i64, u64);
+unit_mappable_integer_impl!(i128, u128);
+unit_mappable_integer_impl!(isize, usize);
+
+macro_rules! unit_mappable_float_impl {
+    ($t:ident) => {
+        impl UnitMappable for $t {
+            #[inline]
+            fn unit_map(min: $t, max: $t, exclusive: bool, u: f64) -> $t {
+                let x = (min as f64 + u * (max as f64 - min as f64)) as $t;
+
+                if exclusive && x >= max {
+                    // the largest value which is less than `max`
+                    if max > 0.0 {
+                        $t::from_bits(max.to_bits() - 1)
+                    } else if max < 0.0 {
+                        $t::from_bits(max.to_bits() + 1)
+                    } else {
+                        -$t::from_bits(1)
+                    }
+                } else if x > max {
+                    max
+                } else {
+                    x
+                }
+            }
+        }
+    };
+}
+
+unit_mappable_float_impl!(f32);
+unit_mappable_float_impl!(f64);
+
+/// Get the bounds of a range for mapping the unit interval, and whether the end bound is exclusive. An unbounded start or end is `X::min_value()` or `X::max_value()`.
+#[inline]
+pub(crate) fn unit_map_bounds<X: UnitMappable, R: RangeBounds<X>>(
+    range: &R,
+    name: &str,
+) -> (X, X, bool) {
+    let min = match range.start_bound() {
+        Bound::Included(&min) => min,
+        Bound::Excluded(_) => panic!("{} called with a start bound which is exclusive", name),
+        Bound::Unbounded => X::min_value(),
+    };
+
+    let (max, exclusive) = match range.end_bound() {
+        Bound::Included(&max) => (max, false),
+        Bound::Excluded(&max) => (max, true),
+        Bound::Unbounded => (X::max_value(), false),
+    };
+
+    if !(min < max || (!exclusive && min == max)) {
+        panic!("{} called with an empty range", name);
+    }
+
+    (min, max, exclusive)
+}
+
+/// Fill with the one-dimensional Sobol sequence, which is the van der Corput sequence in base 2, mapped into a range. The values are evenly spread for any length, and perfectly stratified for lengths of powers of two.
+///
+/// The range can be of integers or floats, as with `random_ranged`. An unbounded start or end is `X::min_value()` or `X::max_value()`, so `..` is [0, 1] for floats and every value for integers. Integers are the floors of the points mapped into the range, so for lengths of powers of two, the numbers of times the values are hit differ by at most one.
+///
+/// Panics if the range is empty, the start bound is exclusive, or the length of `out` is greater than 2<sup>32</sup>.
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut a = [0f64; 4];
+/// random_number::quasi_fill(&mut a, 10.0..20.0);
+///
+/// assert_eq!([10.0, 15.0, 17.5, 12.5], a);
+///
+/// let mut a = [0f32; 4];
+/// random_number::quasi_fill(&mut a, ..);
+///
+/// assert_eq!([0.0, 0.5, 0.75, 0.25], a);
+///
+/// let mut a = [0f32; 4];
+/// random_number::quasi_fill(&mut a, -1.0..);
+///
+/// assert_eq!([-1.0, 0.0, 0.5, -0.5], a);
+///
+/// let mut a = [0u32; 4];
+/// random_number::quasi_fill(&mut a, 0..100);
+///
+/// assert_eq!([0, 50, 75, 25], a);
+///
+/// let mut a = [0i8; 256];
+/// random_number::quasi_fill(&mut a, ..);
+///
+/// a.sort_unstable();
+///
+/// assert!(a.iter().zip(-128..=127).all(|(&a, b)| a == b));
+/// ```
+#[inline]
+pub fn quasi_fill<X: UnitMappable, R: RangeBounds<X>>(out: &mut [X], range: R) {
+    fill_with_sobol(out, range, Sobol::new(1), "quasi_fill")
+}
+
+/// Fill with the one-dimensional Sobol sequence mapped into a range, after it is Owen-scrambled with a seed drawn from `rng`. The values are as evenly spread as those of `quasi_fill`, but each of them is uniformly distributed in the range.
+///
+/// An unbounded start or end is `X::min_value()` or `X::max_value()`, as with `quasi_fill`.
+///
+/// Panics if the range is empty, the start bound is exclusive, or the length of `out` is greater than 2<sup>32</sup>.
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// let mut a = [0f64; 8];
+/// random_number::quasi_fill_scrambled(&mut a, 0.0..1.0, &mut rng);
+///
+/// // one value in each eighth
+/// a.sort_by(|a, b| a.partial_cmp(b).unwrap());
+///
+/// for (i, x) in a.iter().enumerate() {
+///     assert_eq!(i, (x * 8.0) as usize);
+/// }
+/// ```
+#[inline]
+pub fn quasi_fill_scrambled<X: UnitMappable, R: RangeBounds<X>, T: Rng>(
+    out: &mut [X],
+    range: R,
+    rng: &mut T,
+) {
+    fill_with_sobol(out, range, Sobol::new(1).scramble(rng), "quasi_fill_scrambled")
+}
+
+#[inline]
+fn fill_with_sobol<X: UnitMappable, R: RangeBounds<X>>(
+    out: &mut [X],
+    range: R,
+    sobol: Sobol,
+    name: &str,
+) {
+    let (min, max, exclusive) = unit_map_bounds(&range, name);
+
+    if out.len() as u64 > 1 << 32 {
+        panic!("{} called with more than 2^32 values", name);
+    }
+
+    for (x, p) in out.iter_mut().zip(sobol) {
+        *x = X::unit_map(min, max, exclusive, p[0]);
+    }
+}
@@ -0,0 +1,118 @@
+/*!
+Generators for [quickcheck](https://crates.io/crates/quickcheck) `Arbitrary` implementations which generate values with the same range grammar as the `random!` macro. This module is available with the `quickcheck` feature.
+
+The `arbitrary!` macro takes the arguments of the `random!` macro with a `&mut Gen` as the generator, and `shrink_ranged` shrinks a value toward the lower bound of its range.
+
+```rust
+use quickcheck::{Arbitrary, Gen};
+use random_number::quickcheck::{arbitrary, shrink_ranged};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Percentage(u8);
+
+impl Arbitrary for Percentage {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Percentage(arbitrary!(0..=100, g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(shrink_ranged(self.0, 0..=100).map(Percentage))
+    }
+}
+
+let mut g = Gen::new(100);
+
+let p = Percentage::arbitrary(&mut g);
+
+assert!(p.0 <= 100);
+assert!(p.shrink().all(|q| q.0 < p.0));
+```
+*/
+
+use std::ops::{Bound, RangeBounds};
+
+use ::quickcheck::{Arbitrary, Gen};
+
+use crate::{
+    generators::Xoshiro256StarStar,
+    rand::{distributions::uniform::SampleUniform, SeedableRng},
+    random_ranged_with_rng, Bounded, Shrinkable,
+};
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __quickcheck_arbitrary {
+    ($($args:tt)*) => {
+        $crate::__arbitrary!($($args)*)
+    };
+}
+
+/// Generate a random value from the arguments of the `random!` macro, with a `&mut Gen` as the last argument.
+///
+/// ```rust
+/// use quickcheck::Gen;
+/// use random_number::quickcheck::arbitrary;
+///
+/// let mut g = Gen::new(100);
+///
+/// let n: u8 = arbitrary!(1..=10, &mut g);
+/// assert!((1..=10).contains(&n));
+///
+/// let n: i32 = arbitrary!(20, 12, &mut g);
+/// assert!((12..=20).contains(&n));
+///
+/// let _: u64 = arbitrary!(&mut g);
+/// ```
+#[doc(inline)]
+pub use crate::__quickcheck_arbitrary as arbitrary;
+
+/// Generate a random value in a specific range with a quickcheck `Gen`.
+///
+/// Panics if the start bound is exclusive.
+#[inline]
+pub fn arbitrary_ranged<X: SampleUniform + Bounded, R: RangeBounds<X>>(range: R, g: &mut Gen) -> X {
+    let mut rng = Xoshiro256StarStar::seed_from_u64(u64::arbitrary(g));
+
+    random_ranged_with_rng(range, &mut rng)
+}
+
+/// Shrink `value` toward the start of a range. If the start bound is unbounded, the range starts at `X::min_value()`.
+///
+/// The candidates begin at the start of the range and approach `value` by halving the distance to it, so the first candidate is the simplest one. There are no candidates if `value` is not greater than the start of the range.
+///
+/// Panics if the start bound is exclusive.
+///
+/// ## Examples
+///
+/// ```rust
+/// use random_number::quickcheck::shrink_ranged;
+///
+/// assert_eq!(
+///     vec![10, 55, 77, 88, 94, 97, 98, 99],
+///     shrink_ranged(100u8, 10..=200).collect::<Vec<u8>>()
+/// );
+/// ```
+pub fn shrink_ranged<X: Shrinkable + Bounded + 'static, R: RangeBounds<X>>(
+    value: X,
+    range: R,
+) -> Box<dyn Iterator<Item = X>> {
+    let low = match range.start_bound() {
+        Bound::Included(&low) => low,
+        Bound::Excluded(_) => {
+            panic!("shrink_ranged called with a start bound which is exclusive")
+        },
+        Bound::Unbounded => X::min_value(),
+    };
+
+    let mut next = if low < value { Some(low) } else { None };
+
+    Box::new(std::iter::from_fn(move || {
+        let candidate = next?;
+
+        let midpoint = X::midpoint(candidate, value);
+
+        next = if midpoint == candidate { None } else { Some(midpoint) };
+
+        Some(candidate)
+    }))
+}
@@ -0,0 +1,152 @@
+use crate::{
+    default_rng,
+    rand::{
+        distributions::{Bernoulli, Distribution},
+        Rng,
+    },
+};
+
+/// Generate a random boolean which is `true` with the probability `p` with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if `p` is not in the range [0, 1].
+#[inline]
+pub fn random_bool(p: f64) -> bool {
+    random_bool_with_rng(p, &mut default_rng())
+}
+
+/// Generate a random boolean which is `true` with the probability `p` with an existing random number generator.
+///
+/// Panics if `p` is not in the range [0, 1].
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// assert!(random_number::random_bool_with_rng(1.0, &mut rng));
+/// assert!(!random_number::random_bool_with_rng(0.0, &mut rng));
+/// ```
+#[inline]
+pub fn random_bool_with_rng<T: Rng>(p: f64, rng: &mut T) -> bool {
+    rng.gen_bool(p)
+}
+
+/// Generate a random boolean which is `true` with the probability `numerator / denominator` exactly, with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if `denominator` is zero or `numerator` is greater than `denominator`.
+#[inline]
+pub fn random_ratio(numerator: u32, denominator: u32) -> bool {
+    random_ratio_with_rng(numerator, denominator, &mut default_rng())
+}
+
+/// Generate a random boolean which is `true` with the probability `numerator / denominator` exactly, with an existing random number generator. No floating-point number is involved.
+///
+/// Panics if `denominator` is zero or `numerator` is greater than `denominator`.
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// // roll out a feature to 1 in 3 users
+/// let enabled = random_number::random_ratio_with_rng(1, 3, &mut rng);
+///
+/// println!("{}", enabled);
+/// ```
+#[inline]
+pub fn random_ratio_with_rng<T: Rng>(numerator: u32, denominator: u32, rng: &mut T) -> bool {
+    rng.gen_ratio(numerator, denominator)
+}
+
+/// Generate random booleans which are `true` with the probability `p` with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if `p` is not in the range [0, 1].
+#[inline]
+pub fn random_fill_bool(out: &mut [bool], p: f64) {
+    random_fill_bool_with_rng(out, p, &mut default_rng())
+}
+
+/// Generate random booleans which are `true` with the probability `p` with an existing random number generator.
+///
+/// Panics if `p` is not in the range [0, 1].
+#[inline]
+pub fn random_fill_bool_with_rng<T: Rng>(out: &mut [bool], p: f64, rng: &mut T) {
+    let bernoulli = match Bernoulli::new(p) {
+        Ok(bernoulli) => bernoulli,
+        Err(_) => panic!("random_fill_bool_with_rng called with a probability out of [0, 1]"),
+    };
+
+    for x in out.iter_mut() {
+        *x = bernoulli.sample(rng);
+    }
+}
+
+/// Generate a bit set of `n` random bits which are set with the probability `p` with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if `p` is not in the range [0, 1].
+#[inline]
+pub fn random_bitset(n: usize, p: f64) -> Vec<u64> {
+    random_bitset_with_rng(n, p, &mut default_rng())
+}
+
+/// Generate a bit set of `n` random bits which are set with the probability `p` with an existing random number generator.
+///
+/// The bit `i` is `(bits[i / 64] >> (i % 64)) & 1`, and the unused bits of the last word are zero. Instead of drawing a random number for every bit, 64 bits are built at once by combining random words with AND and OR according to the binary digits of `p`, so `p` is rounded down to a multiple of 2<sup>-32</sup> and each word takes at most 32 random numbers. For example, `p = 0.5` takes one random number per word and `p = 0.25` takes two.
+///
+/// Panics if `p` is not in the range [0, 1].
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// let bits = random_number::random_bitset_with_rng(100, 0.3, &mut rng);
+///
+/// assert_eq!(2, bits.len());
+/// assert_eq!(0, bits[1] >> 36);
+///
+/// let bits = random_number::random_bitset_with_rng(100, 1.0, &mut rng);
+///
+/// assert_eq!(100, bits.iter().map(|word| word.count_ones()).sum::<u32>());
+/// ```
+pub fn random_bitset_with_rng<T: Rng>(n: usize, p: f64, rng: &mut T) -> Vec<u64> {
+    if !(0.0..=1.0).contains(&p) {
+        panic!("random_bitset_with_rng called with a probability out of [0, 1]");
+    }
+
+    // `p` as a 32-bit fixed-point number, which is `1 << 32` only if `p` is 1
+    let digits = (p * (1u64 << 32) as f64) as u64;
+
+    let mut bits = vec![0u64; (n + 63) / 64];
+
+    for word in bits.iter_mut() {
+        *word = if digits >> 32 != 0 {
+            !0
+        } else if digits == 0 {
+            0
+        } else {
+            // from the lowest digit to the highest, a 1 raises the probability of each bit from `q` to `(1 + q) / 2`, and a 0 lowers it to `q / 2`
+            let mut w = 0u64;
+
+            for i in digits.trailing_zeros()..32 {
+                let r = rng.next_u64();
+
+                if (digits >> i) & 1 == 1 {
+                    w |= r;
+                } else {
+                    w &= r;
+                }
+            }
+
+            w
+        };
+    }
+
+    if n % 64 != 0 {
+        if let Some(last) = bits.last_mut() {
+            *last &= (1u64 << (n % 64)) - 1;
+        }
+    }
+
+    bits
+}
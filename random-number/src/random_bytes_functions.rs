@@ -0,0 +1,45 @@
+use crate::{default_rng, rand::Rng};
+
+/// Generate `n` random bytes with a new lazily-initialized thread-local random number generator.
+#[inline]
+pub fn random_bytes(n: usize) -> Vec<u8> {
+    random_bytes_with_rng(n, &mut default_rng())
+}
+
+/// Generate `n` random bytes with an existing random number generator.
+#[inline]
+pub fn random_bytes_with_rng<T: Rng>(n: usize, rng: &mut T) -> Vec<u8> {
+    let mut bytes = vec![0u8; n];
+
+    rng.fill_bytes(&mut bytes);
+
+    bytes
+}
+
+/// Generate an array of `N` random bytes with a new lazily-initialized thread-local random number generator.
+#[inline]
+pub fn random_bytes_array<const N: usize>() -> [u8; N] {
+    random_bytes_array_with_rng(&mut default_rng())
+}
+
+/// Generate an array of `N` random bytes with an existing random number generator.
+#[inline]
+pub fn random_bytes_array_with_rng<const N: usize, T: Rng>(rng: &mut T) -> [u8; N] {
+    let mut bytes = [0u8; N];
+
+    rng.fill_bytes(&mut bytes);
+
+    bytes
+}
+
+/// Fill a byte slice with random bytes with a new lazily-initialized thread-local random number generator.
+#[inline]
+pub fn random_fill_bytes(out: &mut [u8]) {
+    random_fill_bytes_with_rng(out, &mut default_rng())
+}
+
+/// Fill a byte slice with random bytes with an existing random number generator.
+#[inline]
+pub fn random_fill_bytes_with_rng<T: Rng>(out: &mut [u8], rng: &mut T) {
+    rng.fill_bytes(out);
+}
@@ -0,0 +1,77 @@
+use std::ops::RangeBounds;
+
+use crate::{
+    generators::{SplittableRng, Xoshiro256StarStar},
+    rand::{distributions::uniform::SampleUniform, SeedableRng},
+    random_fill_ranged_with_rng, random_fill_with_rng, Bounded,
+};
+
+/// Create the random number generator of the chunk `chunk_index` of a chunked fill with the seed `seed`. It is `Xoshiro256StarStar::seed_from_u64(seed).fork(chunk_index)`.
+#[inline]
+pub fn chunk_rng(seed: u64, chunk_index: u64) -> Xoshiro256StarStar {
+    Xoshiro256StarStar::seed_from_u64(seed).fork(chunk_index)
+}
+
+/// Generate random values in the range of the output type, chunk by chunk. Each chunk of `chunk_size` values (the last one may be shorter) gets its own reproducible stream from `chunk_rng(seed, chunk_index)`, so any chunk can be regenerated alone, or the chunks can be filled in parallel.
+///
+/// Panics if `chunk_size` is zero.
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut a = [0u32; 1000];
+/// random_number::random_fill_chunked(&mut a, 100, 42);
+///
+/// // regenerate the shard 3 without the others
+/// let mut shard = [0u32; 100];
+/// random_number::random_fill_with_rng(
+///     &mut shard,
+///     &mut random_number::chunk_rng(42, 3),
+/// );
+///
+/// assert_eq!(&a[300..400], &shard[..]);
+/// ```
+#[inline]
+pub fn random_fill_chunked<X: SampleUniform + Bounded>(
+    out: &mut [X],
+    chunk_size: usize,
+    seed: u64,
+) {
+    for (i, chunk) in out.chunks_mut(chunk_size).enumerate() {
+        random_fill_with_rng(chunk, &mut chunk_rng(seed, i as u64));
+    }
+}
+
+/// Generate random values in a specific range, chunk by chunk. Each chunk of `chunk_size` values (the last one may be shorter) gets its own reproducible stream from `chunk_rng(seed, chunk_index)`, so any chunk can be regenerated alone, or the chunks can be filled in parallel.
+///
+/// Panics if `chunk_size` is zero or the start bound is exclusive.
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut a = [0u8; 64];
+/// random_number::random_fill_chunked_ranged(&mut a, 1..=6, 16, 7);
+///
+/// let mut b = [0u8; 64];
+///
+/// for (i, chunk) in b.chunks_mut(16).enumerate().rev() {
+///     random_number::random_fill_ranged_with_rng(
+///         chunk,
+///         1..=6,
+///         &mut random_number::chunk_rng(7, i as u64),
+///     );
+/// }
+///
+/// assert_eq!(a, b);
+/// ```
+#[inline]
+pub fn random_fill_chunked_ranged<X: SampleUniform + Bounded, R: RangeBounds<X> + Clone>(
+    out: &mut [X],
+    range: R,
+    chunk_size: usize,
+    seed: u64,
+) {
+    for (i, chunk) in out.chunks_mut(chunk_size).enumerate() {
+        random_fill_ranged_with_rng(chunk, range.clone(), &mut chunk_rng(seed, i as u64));
+    }
+}
@@ -0,0 +1,190 @@
+use std::ops::{Bound, RangeBounds};
+
+use crate::{
+    default_rng,
+    rand::{
+        distributions::{
+            uniform::{SampleUniform, Uniform},
+            Distribution,
+        },
+        Rng,
+    },
+    Bounded,
+};
+
+/// Tuples and arrays whose components are sampled independently, each in its own range.
+pub trait SampleComponents: Sized {
+    /// Generate a value whose every component is in the range [`min`, `max_exclusive`) of that component.
+    ///
+    /// Panics if `min >= max_exclusive` for any component.
+    fn sample_exclusively_with_rng<T: Rng>(min: Self, max_exclusive: Self, rng: &mut T) -> Self;
+
+    /// Generate a value whose every component is in the range [`min`, `max_inclusive`] of that component.
+    ///
+    /// Panics if `min > max_inclusive` for any component.
+    fn sample_inclusively_with_rng<T: Rng>(min: Self, max_inclusive: Self, rng: &mut T) -> Self;
+}
+
+macro_rules! sample_components_tuple_impl {
+    ($($x:ident $i:tt),+) => {
+        impl<$($x: SampleUniform),+> SampleComponents for ($($x,)+) {
+            #[inline]
+            fn sample_exclusively_with_rng<T: Rng>(
+                min: Self,
+                max_exclusive: Self,
+                rng: &mut T,
+            ) -> Self {
+                ($(Uniform::new(min.$i, max_exclusive.$i).sample(rng),)+)
+            }
+
+            #[inline]
+            fn sample_inclusively_with_rng<T: Rng>(
+                min: Self,
+                max_inclusive: Self,
+                rng: &mut T,
+            ) -> Self {
+                ($(Uniform::new_inclusive(min.$i, max_inclusive.$i).sample(rng),)+)
+            }
+        }
+    };
+}
+
+sample_components_tuple_impl!(A 0);
+sample_components_tuple_impl!(A 0, B 1);
+sample_components_tuple_impl!(A 0, B 1, C 2);
+sample_components_tuple_impl!(A 0, B 1, C 2, D 3);
+sample_components_tuple_impl!(A 0, B 1, C 2, D 3, E 4);
+sample_components_tuple_impl!(A 0, B 1, C 2, D 3, E 4, F 5);
+sample_components_tuple_impl!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+sample_components_tuple_impl!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+sample_components_tuple_impl!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+sample_components_tuple_impl!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+sample_components_tuple_impl!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+sample_components_tuple_impl!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+impl<X: SampleUniform, const N: usize> SampleComponents for [X; N] {
+    #[inline]
+    fn sample_exclusively_with_rng<T: Rng>(min: Self, max_exclusive: Self, rng: &mut T) -> Self {
+        let mut out = min;
+
+        for (x, max_exclusive) in out.iter_mut().zip(max_exclusive.iter()) {
+            *x = Uniform::new(&*x, max_exclusive).sample(rng);
+        }
+
+        out
+    }
+
+    #[inline]
+    fn sample_inclusively_with_rng<T: Rng>(min: Self, max_inclusive: Self, rng: &mut T) -> Self {
+        let mut out = min;
+
+        for (x, max_inclusive) in out.iter_mut().zip(max_inclusive.iter()) {
+            *x = Uniform::new_inclusive(&*x, max_inclusive).sample(rng);
+        }
+
+        out
+    }
+}
+
+/// Resolve a range into its minimum, its maximum and whether the maximum is inclusive.
+fn component_bounds<X: Bounded + Clone, R: RangeBounds<X>>(
+    range: &R,
+    caller: &str,
+) -> (X, X, bool) {
+    let min = match range.start_bound() {
+        Bound::Excluded(_) => panic!("{} called with a start bound which is exclusive", caller),
+        Bound::Included(min) => min.clone(),
+        Bound::Unbounded => X::min_value(),
+    };
+
+    match range.end_bound() {
+        Bound::Excluded(max_exclusive) => (min, max_exclusive.clone(), false),
+        Bound::Included(max_inclusive) => (min, max_inclusive.clone(), true),
+        Bound::Unbounded => (min, X::max_value(), true),
+    }
+}
+
+/// Generate a random tuple or array whose every component is in the range of that component, with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if the start bound is exclusive.
+#[inline]
+pub fn random_components_ranged<X: SampleComponents + Bounded + Clone, R: RangeBounds<X>>(
+    range: R,
+) -> X {
+    random_components_ranged_with_rng(range, &mut default_rng())
+}
+
+/// Generate a random tuple or array whose every component is in the range of that component, with an existing random number generator.
+///
+/// Panics if the start bound is exclusive.
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// let (x, y): (u8, i16) = random_number::random_components_ranged_with_rng(
+///     (0, -5)..=(10, 5),
+///     &mut rng,
+/// );
+///
+/// assert!(x <= 10 && -5 <= y && y <= 5);
+///
+/// let a: [u8; 4] = random_number::random_components_ranged_with_rng(
+///     [0; 4]..[10; 4],
+///     &mut rng,
+/// );
+///
+/// assert!(a.iter().all(|&a| a < 10));
+/// ```
+pub fn random_components_ranged_with_rng<
+    X: SampleComponents + Bounded + Clone,
+    R: RangeBounds<X>,
+    T: Rng,
+>(
+    range: R,
+    rng: &mut T,
+) -> X {
+    let (min, max, inclusive) = component_bounds(&range, "random_components_ranged_with_rng");
+
+    if inclusive {
+        X::sample_inclusively_with_rng(min, max, rng)
+    } else {
+        X::sample_exclusively_with_rng(min, max, rng)
+    }
+}
+
+/// Generate random tuples or arrays whose every component is in the range of that component, with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if the start bound is exclusive.
+#[inline]
+pub fn random_fill_components_ranged<X: SampleComponents + Bounded + Clone, R: RangeBounds<X>>(
+    out: &mut [X],
+    range: R,
+) {
+    random_fill_components_ranged_with_rng(out, range, &mut default_rng())
+}
+
+/// Generate random tuples or arrays whose every component is in the range of that component, with an existing random number generator.
+///
+/// Panics if the start bound is exclusive.
+#[inline]
+pub fn random_fill_components_ranged_with_rng<
+    X: SampleComponents + Bounded + Clone,
+    R: RangeBounds<X>,
+    T: Rng,
+>(
+    out: &mut [X],
+    range: R,
+    rng: &mut T,
+) {
+    let (min, max, inclusive) = component_bounds(&range, "random_fill_components_ranged_with_rng");
+
+    for x in out.iter_mut() {
+        *x = if inclusive {
+            X::sample_inclusively_with_rng(min.clone(), max.clone(), rng)
+        } else {
+            X::sample_exclusively_with_rng(min.clone(), max.clone(), rng)
+        };
+    }
+}
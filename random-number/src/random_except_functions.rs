@@ -0,0 +1,289 @@
+use std::{
+    marker::PhantomData,
+    ops::{Bound, Range, RangeBounds, RangeInclusive},
+};
+
+use crate::{default_rng, rand::Rng, Discrete};
+
+/// Values or ranges of values which can be excluded from a random range.
+pub trait Exclusion<X: Discrete> {
+    /// The excluded values as an inclusive range of indices, or `None` if nothing is excluded.
+    fn excluded_indices(&self) -> Option<(u128, u128)>;
+}
+
+impl<X: Discrete> Exclusion<X> for X {
+    #[inline]
+    fn excluded_indices(&self) -> Option<(u128, u128)> {
+        let index = self.to_index();
+
+        Some((index, index))
+    }
+}
+
+impl<X: Discrete> Exclusion<X> for Range<X> {
+    #[inline]
+    fn excluded_indices(&self) -> Option<(u128, u128)> {
+        if self.start < self.end {
+            Some((self.start.to_index(), self.end.to_index() - 1))
+        } else {
+            None
+        }
+    }
+}
+
+impl<X: Discrete> Exclusion<X> for RangeInclusive<X> {
+    #[inline]
+    fn excluded_indices(&self) -> Option<(u128, u128)> {
+        if self.start() <= self.end() {
+            Some((self.start().to_index(), self.end().to_index()))
+        } else {
+            None
+        }
+    }
+}
+
+/// Convert a range into an inclusive range of indices, or `None` if the range is empty.
+///
+/// Panics if the start bound is exclusive.
+pub(crate) fn index_bounds<X: Discrete, R: RangeBounds<X>>(
+    range: &R,
+    caller: &str,
+) -> Option<(u128, u128)> {
+    let start = match range.start_bound() {
+        Bound::Excluded(_) => panic!("{} called with a start bound which is exclusive", caller),
+        Bound::Included(min) => min.to_index(),
+        Bound::Unbounded => X::min_value().to_index(),
+    };
+
+    let end = match range.end_bound() {
+        Bound::Excluded(max_exclusive) => {
+            let max_exclusive = max_exclusive.to_index();
+
+            if max_exclusive == 0 {
+                return None;
+            }
+
+            max_exclusive - 1
+        },
+        Bound::Included(max_inclusive) => max_inclusive.to_index(),
+        Bound::Unbounded => X::max_value().to_index(),
+    };
+
+    if start > end {
+        None
+    } else {
+        Some((start, end))
+    }
+}
+
+/// Sample indices uniformly from an inclusive range of indices except for some excluded ranges, by remapping the index space of the remaining indices instead of retrying.
+pub(crate) struct IndexSampler {
+    /// The start index of each remaining segment and the number of remaining indices before it.
+    segments: Vec<(u128, u128)>,
+    /// The number of remaining indices, or `None` for the full `u128` range.
+    total:    Option<u128>,
+}
+
+impl IndexSampler {
+    /// Returns `None` if every index is excluded.
+    pub(crate) fn new(start: u128, end: u128, mut excluded: Vec<(u128, u128)>) -> Option<Self> {
+        excluded.sort_unstable();
+
+        let mut segments = Vec::with_capacity(excluded.len() + 1);
+        let mut total: Option<u128> = Some(0);
+        let mut next = Some(start);
+
+        let mut push = |a: u128, b: u128, total: &mut Option<u128>| {
+            if let Some(n) = *total {
+                segments.push((a, n));
+
+                *total = n.checked_add(b - a).and_then(|n| n.checked_add(1));
+            }
+        };
+
+        for (a, b) in excluded {
+            let current = match next {
+                Some(current) => current,
+                None => break,
+            };
+
+            if b < current {
+                continue;
+            }
+
+            if a > end {
+                break;
+            }
+
+            if a > current {
+                push(current, a - 1, &mut total);
+            }
+
+            next = if b >= end { None } else { Some(b + 1) };
+        }
+
+        if let Some(current) = next {
+            push(current, end, &mut total);
+        }
+
+        if segments.is_empty() {
+            None
+        } else {
+            Some(IndexSampler {
+                segments,
+                total,
+            })
+        }
+    }
+
+    #[inline]
+    pub(crate) fn sample<T: Rng>(&self, rng: &mut T) -> u128 {
+        let index = match self.total {
+            Some(total) => rng.gen_range(0..total),
+            None => return rng.gen(),
+        };
+
+        let i = match self.segments.binary_search_by(|&(_, offset)| offset.cmp(&index)) {
+            Ok(i) => i,
+            Err(i) => i - 1,
+        };
+
+        let (start, offset) = self.segments[i];
+
+        start + (index - offset)
+    }
+}
+
+fn except_sampler<X: Discrete, R: RangeBounds<X>>(
+    range: &R,
+    excluded: Vec<(u128, u128)>,
+    caller: &str,
+) -> IndexSampler {
+    let sampler = index_bounds(range, caller)
+        .and_then(|(start, end)| IndexSampler::new(start, end, excluded));
+
+    match sampler {
+        Some(sampler) => sampler,
+        None => panic!("{} called with a range whose values are all excluded", caller),
+    }
+}
+
+#[inline]
+fn excluded_indices<X: Discrete, E: Exclusion<X>>(excluded: &[E]) -> Vec<(u128, u128)> {
+    excluded.iter().filter_map(|e| e.excluded_indices()).collect()
+}
+
+/// The excluded values and ranges of an `except [...]` clause, which may have different types, such as `except [3, 5..7]`. This is used by the `random!` and `random_fill!` macros.
+#[doc(hidden)]
+#[derive(Debug, Clone)]
+pub struct MacroExclusions<X> {
+    indices: Vec<(u128, u128)>,
+    phantom: PhantomData<X>,
+}
+
+impl<X: Discrete> MacroExclusions<X> {
+    #[inline]
+    pub fn new() -> Self {
+        MacroExclusions {
+            indices: Vec::new(), phantom: PhantomData
+        }
+    }
+
+    #[inline]
+    pub fn push<E: Exclusion<X>>(&mut self, excluded: E) {
+        self.indices.extend(excluded.excluded_indices());
+    }
+
+    #[inline]
+    pub fn sample_with_rng<R: RangeBounds<X>, T: Rng>(self, range: R, rng: &mut T) -> X {
+        X::from_index(except_sampler(&range, self.indices, "random_except_with_rng").sample(rng))
+    }
+
+    #[inline]
+    pub fn fill_with_rng<R: RangeBounds<X>, T: Rng>(self, out: &mut [X], range: R, rng: &mut T) {
+        let sampler = except_sampler(&range, self.indices, "random_fill_except_with_rng");
+
+        for x in out.iter_mut() {
+            *x = X::from_index(sampler.sample(rng));
+        }
+    }
+}
+
+impl<X: Discrete> Default for MacroExclusions<X> {
+    #[inline]
+    fn default() -> Self {
+        MacroExclusions::new()
+    }
+}
+
+/// Generate a random value in a specific range except for the `excluded` values or ranges with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if the start bound is exclusive or every value in the range is excluded.
+#[inline]
+pub fn random_except<X: Discrete, R: RangeBounds<X>, E: Exclusion<X>>(
+    range: R,
+    excluded: &[E],
+) -> X {
+    random_except_with_rng(range, excluded, &mut default_rng())
+}
+
+/// Generate a random value in a specific range except for the `excluded` values or ranges with an existing random number generator.
+///
+/// Panics if the start bound is exclusive or every value in the range is excluded.
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// for _ in 0..100 {
+///     let n: u8 =
+///         random_number::random_except_with_rng(1..=5, &[2, 4], &mut rng);
+///
+///     assert!(n == 1 || n == 3 || n == 5);
+///
+///     let n: i8 =
+///         random_number::random_except_with_rng(-3..3, &[-2..=1], &mut rng);
+///
+///     assert!(n == -3 || n == 2);
+/// }
+/// ```
+#[inline]
+pub fn random_except_with_rng<X: Discrete, R: RangeBounds<X>, E: Exclusion<X>, T: Rng>(
+    range: R,
+    excluded: &[E],
+    rng: &mut T,
+) -> X {
+    X::from_index(
+        except_sampler(&range, excluded_indices(excluded), "random_except_with_rng").sample(rng),
+    )
+}
+
+/// Generate random values in a specific range except for the `excluded` values or ranges with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if the start bound is exclusive or every value in the range is excluded.
+#[inline]
+pub fn random_fill_except<X: Discrete, R: RangeBounds<X>, E: Exclusion<X>>(
+    out: &mut [X],
+    range: R,
+    excluded: &[E],
+) {
+    random_fill_except_with_rng(out, range, excluded, &mut default_rng())
+}
+
+/// Generate random values in a specific range except for the `excluded` values or ranges with an existing random number generator.
+///
+/// Panics if the start bound is exclusive or every value in the range is excluded.
+#[inline]
+pub fn random_fill_except_with_rng<X: Discrete, R: RangeBounds<X>, E: Exclusion<X>, T: Rng>(
+    out: &mut [X],
+    range: R,
+    excluded: &[E],
+    rng: &mut T,
+) {
+    let sampler = except_sampler(&range, excluded_indices(excluded), "random_fill_except_with_rng");
+
+    for x in out.iter_mut() {
+        *x = X::from_index(sampler.sample(rng));
+    }
+}
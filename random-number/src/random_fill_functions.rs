@@ -4,12 +4,13 @@ use std::{
 };
 
 use crate::{
+    default_rng,
     rand::{
         distributions::{
             uniform::{SampleBorrow, SampleUniform, Uniform},
             Distribution,
         },
-        thread_rng, Rng,
+        Rng,
     },
     Bounded,
 };
@@ -23,7 +24,7 @@ pub fn random_fill_exclusively<X: SampleUniform, B1: SampleBorrow<X>, B2: Sample
     min: B1,
     max_exclusive: B2,
 ) {
-    random_fill_exclusively_with_rng(out, min, max_exclusive, &mut thread_rng())
+    random_fill_exclusively_with_rng(out, min, max_exclusive, &mut default_rng())
 }
 
 /// Generate random values in the range [`min`, `max_exclusive`) with an existing random number generator.
@@ -57,7 +58,7 @@ pub fn random_fill_inclusively<X: SampleUniform, B1: SampleBorrow<X>, B2: Sample
     min: B1,
     max_inclusive: B2,
 ) {
-    random_fill_inclusively_with_rng(out, min, max_inclusive, &mut thread_rng());
+    random_fill_inclusively_with_rng(out, min, max_inclusive, &mut default_rng());
 }
 
 /// Generate random values in the range [`min`, `max_inclusive`] with an existing random number generator.
@@ -92,7 +93,7 @@ pub fn random_fill_inclusively_cmp<X: SampleUniform + Ord + Clone, B: SampleBorr
     a: B,
     b: B,
 ) {
-    random_fill_inclusively_cmp_with_rng(out, a, b, &mut thread_rng());
+    random_fill_inclusively_cmp_with_rng(out, a, b, &mut default_rng());
 }
 
 /// Generate random values in the range [`a`, `b`] or [`b`, `a`] with an existing random number generator.
@@ -121,19 +122,21 @@ pub fn random_fill_inclusively_cmp_with_rng<
 /// Generate random values in the range of the output type with a new lazily-initialized thread-local random number generator.
 #[inline]
 pub fn random_fill<X: SampleUniform + Bounded>(out: &mut [X]) {
-    random_fill_with_rng(out, &mut thread_rng())
+    random_fill_with_rng(out, &mut default_rng())
 }
 
 /// Generate random values in the range of the output type with an existing random number generator.
+///
+/// For `u8` and `i8`, the slice is filled directly by `RngCore::fill_bytes`.
 #[inline]
 pub fn random_fill_with_rng<X: SampleUniform + Bounded, T: Rng>(out: &mut [X], rng: &mut T) {
-    random_fill_inclusively_with_rng(out, X::min_value(), X::max_value(), rng);
+    X::fill_full_range(out, rng);
 }
 
 /// Generate random values in the range [`min`, `Bounded::max_value()`] with a new lazily-initialized thread-local random number generator.
 #[inline]
 pub fn random_fill_at_least<X: SampleUniform + Bounded, B: SampleBorrow<X>>(out: &mut [X], min: B) {
-    random_fill_at_least_with_rng(out, min, &mut thread_rng());
+    random_fill_at_least_with_rng(out, min, &mut default_rng());
 }
 
 /// Generate random values in the range [`min`, `X::max_value()`] with an existing random number generator.
@@ -152,7 +155,7 @@ pub fn random_fill_at_most<X: SampleUniform + Bounded, B: SampleBorrow<X>>(
     out: &mut [X],
     max_inclusive: B,
 ) {
-    random_fill_at_most_with_rng(out, max_inclusive, &mut thread_rng());
+    random_fill_at_most_with_rng(out, max_inclusive, &mut default_rng());
 }
 
 /// Generate random values in the range [`X::min_value()`, `max_inclusive`] with an existing random number generator.
@@ -173,7 +176,7 @@ pub fn random_fill_at_most_exclusively<X: SampleUniform + Bounded, B: SampleBorr
     out: &mut [X],
     max_exclusive: B,
 ) {
-    random_fill_at_most_exclusively_with_rng(out, max_exclusive, &mut thread_rng());
+    random_fill_at_most_exclusively_with_rng(out, max_exclusive, &mut default_rng());
 }
 
 /// Generate random values in the range [`X::min_value()`, `max_exclusive`) with an existing random number generator.
@@ -197,7 +200,7 @@ pub fn random_fill_at_most_exclusively_with_rng<
 /// Panics if the start bound is exclusive.
 #[inline]
 pub fn random_fill_ranged<X: SampleUniform + Bounded, R: RangeBounds<X>>(out: &mut [X], range: R) {
-    random_fill_ranged_with_rng(out, range, &mut thread_rng())
+    random_fill_ranged_with_rng(out, range, &mut default_rng())
 }
 
 /// Generate random values in a specific range with an existing random number generator.
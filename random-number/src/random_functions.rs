@@ -4,12 +4,13 @@ use std::{
 };
 
 use crate::{
+    default_rng,
     rand::{
         distributions::{
             uniform::{SampleBorrow, SampleUniform, Uniform},
             Distribution,
         },
-        thread_rng, Rng,
+        Rng,
     },
     Bounded,
 };
@@ -22,7 +23,7 @@ pub fn random_exclusively<X: SampleUniform, B1: SampleBorrow<X>, B2: SampleBorro
     min: B1,
     max_exclusive: B2,
 ) -> X {
-    random_exclusively_with_rng(min, max_exclusive, &mut thread_rng())
+    random_exclusively_with_rng(min, max_exclusive, &mut default_rng())
 }
 
 /// Generate a random value in the range [`min`, `max_exclusive`) with an existing random number generator.
@@ -52,7 +53,7 @@ pub fn random_inclusively<X: SampleUniform, B1: SampleBorrow<X>, B2: SampleBorro
     min: B1,
     max_inclusive: B2,
 ) -> X {
-    random_inclusively_with_rng(min, max_inclusive, &mut thread_rng())
+    random_inclusively_with_rng(min, max_inclusive, &mut default_rng())
 }
 
 /// Generate a random value in the range [`min`, `max_inclusive`] with an existing random number generator.
@@ -77,7 +78,7 @@ pub fn random_inclusively_with_rng<
 /// Panics if a and b can not be compared.
 #[inline]
 pub fn random_inclusively_cmp<X: SampleUniform + Ord + Clone, B: SampleBorrow<X>>(a: B, b: B) -> X {
-    random_inclusively_cmp_with_rng(a, b, &mut thread_rng())
+    random_inclusively_cmp_with_rng(a, b, &mut default_rng())
 }
 
 /// Generate a random value in the range [`a`, `b`] or [`b`, `a`] with an existing random number generator.
@@ -101,7 +102,7 @@ pub fn random_inclusively_cmp_with_rng<
 /// Generate a random value in the range of the output type with a new lazily-initialized thread-local random number generator.
 #[inline]
 pub fn random<X: SampleUniform + Bounded>() -> X {
-    random_with_rng(&mut thread_rng())
+    random_with_rng(&mut default_rng())
 }
 
 /// Generate a random value in the range of the output type with an existing random number generator.
@@ -113,7 +114,7 @@ pub fn random_with_rng<X: SampleUniform + Bounded, T: Rng>(rng: &mut T) -> X {
 /// Generate a random value in the range [`min`, `Bounded::max_value()`] with a new lazily-initialized thread-local random number generator.
 #[inline]
 pub fn random_at_least<X: SampleUniform + Bounded, B: SampleBorrow<X>>(min: B) -> X {
-    random_at_least_with_rng(min, &mut thread_rng())
+    random_at_least_with_rng(min, &mut default_rng())
 }
 
 /// Generate a random value in the range [`min`, `X::max_value()`] with an existing random number generator.
@@ -128,7 +129,7 @@ pub fn random_at_least_with_rng<X: SampleUniform + Bounded, B: SampleBorrow<X>,
 /// Generate a random value in the range [`X::min_value()`, `max_inclusive`] with a new lazily-initialized thread-local random number generator.
 #[inline]
 pub fn random_at_most<X: SampleUniform + Bounded, B: SampleBorrow<X>>(max_inclusive: B) -> X {
-    random_at_most_with_rng(max_inclusive, &mut thread_rng())
+    random_at_most_with_rng(max_inclusive, &mut default_rng())
 }
 
 /// Generate a random value in the range [`X::min_value()`, `max_inclusive`] with an existing random number generator.
@@ -147,7 +148,7 @@ pub fn random_at_most_with_rng<X: SampleUniform + Bounded, B: SampleBorrow<X>, T
 pub fn random_at_most_exclusively<X: SampleUniform + Bounded, B: SampleBorrow<X>>(
     max_exclusive: B,
 ) -> X {
-    random_at_most_exclusively_with_rng(max_exclusive, &mut thread_rng())
+    random_at_most_exclusively_with_rng(max_exclusive, &mut default_rng())
 }
 
 /// Generate a random value in the range [`X::min_value()`, `max_exclusive`) with an existing random number generator.
@@ -170,7 +171,7 @@ pub fn random_at_most_exclusively_with_rng<
 /// Panics if the start bound is exclusive.
 #[inline]
 pub fn random_ranged<X: SampleUniform + Bounded, R: RangeBounds<X>>(range: R) -> X {
-    random_ranged_with_rng(range, &mut thread_rng())
+    random_ranged_with_rng(range, &mut default_rng())
 }
 
 /// Generate a random value in a specific range with an existing random number generator.
@@ -0,0 +1,64 @@
+use std::ops::RangeBounds;
+
+use crate::{
+    generators::Philox4x32, rand::distributions::uniform::SampleUniform, random_ranged_with_rng,
+    Bounded,
+};
+
+/// Create the counter-based random number generator of the index `index` with the seed `seed`. Every index owns 2<sup>64</sup> blocks of the counters of Philox4x32, so the values of different indices never share random numbers.
+#[inline]
+pub fn index_rng(seed: u64, index: u64) -> Philox4x32 {
+    Philox4x32::new(seed, u128::from(index) << 64)
+}
+
+/// Generate the value at `index` of a virtual random array with the seed `seed`, in a specific range, without generating the values before it.
+///
+/// Panics if the start bound is exclusive.
+///
+/// ## Examples
+///
+/// ```rust
+/// // the height of the tile 1000000 of a procedural world
+/// let height: u8 = random_number::random_at_index(2024, 1_000_000, 0..=100);
+///
+/// assert!(height <= 100);
+/// assert_eq!(
+///     height,
+///     random_number::random_at_index(2024, 1_000_000, 0..=100)
+/// );
+/// ```
+#[inline]
+pub fn random_at_index<X: SampleUniform + Bounded, R: RangeBounds<X>>(
+    seed: u64,
+    index: u64,
+    range: R,
+) -> X {
+    random_ranged_with_rng(range, &mut index_rng(seed, index))
+}
+
+/// Generate the values from `start` of a virtual random array with the seed `seed`, in a specific range, so any window of the array can be regenerated in O(window). `out[i]` is `random_at_index(seed, start + i, range)`.
+///
+/// Panics if the start bound is exclusive.
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut a = [0u16; 100];
+/// random_number::random_fill_from_index(&mut a, 7, 0, ..);
+///
+/// let mut window = [0u16; 10];
+/// random_number::random_fill_from_index(&mut window, 7, 40, ..);
+///
+/// assert_eq!(&a[40..50], &window[..]);
+/// ```
+#[inline]
+pub fn random_fill_from_index<X: SampleUniform + Bounded, R: RangeBounds<X> + Clone>(
+    out: &mut [X],
+    seed: u64,
+    start: u64,
+    range: R,
+) {
+    for (i, x) in out.iter_mut().enumerate() {
+        *x = random_at_index(seed, start.wrapping_add(i as u64), range.clone());
+    }
+}
@@ -0,0 +1,532 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    marker::PhantomData,
+    net::{AddrParseError, IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    ops::{Range, RangeBounds, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive},
+};
+
+use crate::{
+    default_rng,
+    rand::{
+        distributions::uniform::{SampleBorrow, SampleUniform},
+        Rng,
+    },
+    random_at_least_with_rng, random_at_most_exclusively_with_rng, random_at_most_with_rng,
+    random_except_functions::{index_bounds, IndexSampler},
+    random_exclusively_with_rng, random_inclusively_with_rng, random_ranged_with_rng, Bounded,
+};
+
+/// Address ranges which can be excluded when generating random IP addresses.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct IpExclusion {
+    /// Exclude the network address and the broadcast address of an IPv4 subnet, or the subnet-router anycast address of an IPv6 subnet. Subnets with fewer than four addresses are left untouched.
+    pub network_and_broadcast: bool,
+    /// Exclude private and shared address space, such as `10.0.0.0/8`, `100.64.0.0/10`, `172.16.0.0/12`, `192.168.0.0/16` and `fc00::/7`.
+    pub private:               bool,
+    /// Exclude other special-purpose address space, such as unspecified, loopback, link-local, multicast, documentation and benchmarking ranges.
+    pub reserved:              bool,
+}
+
+/// Errors of parsing a CIDR block or generating an address from it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CidrError {
+    /// The CIDR block is not in the `address/prefix` form.
+    InvalidFormat,
+    /// The address part can not be parsed.
+    InvalidAddress(AddrParseError),
+    /// The prefix length is not a number or is too long for the address family.
+    InvalidPrefix,
+    /// Every address in the CIDR block is excluded.
+    Empty,
+}
+
+impl Display for CidrError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CidrError::InvalidFormat => {
+                f.write_str("the CIDR block is not in the address/prefix form")
+            },
+            CidrError::InvalidAddress(error) => Display::fmt(error, f),
+            CidrError::InvalidPrefix => f.write_str("the prefix length is invalid"),
+            CidrError::Empty => f.write_str("every address in the CIDR block is excluded"),
+        }
+    }
+}
+
+impl Error for CidrError {}
+
+impl From<AddrParseError> for CidrError {
+    #[inline]
+    fn from(error: AddrParseError) -> Self {
+        CidrError::InvalidAddress(error)
+    }
+}
+
+const IPV4_PRIVATE: [(u32, u8); 4] =
+    [(0x0A00_0000, 8), (0x6440_0000, 10), (0xAC10_0000, 12), (0xC0A8_0000, 16)];
+
+const IPV4_RESERVED: [(u32, u8); 10] = [
+    (0x0000_0000, 8),
+    (0x7F00_0000, 8),
+    (0xA9FE_0000, 16),
+    (0xC000_0000, 24),
+    (0xC000_0200, 24),
+    (0xC612_0000, 15),
+    (0xC633_6400, 24),
+    (0xCB00_7100, 24),
+    (0xE000_0000, 4),
+    (0xF000_0000, 4),
+];
+
+const IPV6_PRIVATE: [(u128, u8); 1] = [(0xFC00 << 112, 7)];
+
+const IPV6_RESERVED: [(u128, u8); 9] = [
+    (0, 128),
+    (1, 128),
+    (0xFFFF_0000_0000, 96),
+    (0x0064_FF9B << 96, 96),
+    (0x0100 << 112, 64),
+    (0x2001_0DB8 << 96, 32),
+    (0xFE80 << 112, 10),
+    (0xFEC0 << 112, 10),
+    (0xFF00 << 112, 8),
+];
+
+#[inline]
+fn ipv4_block(network: u32, prefix: u8) -> (u128, u128) {
+    let mask = if prefix == 0 { 0 } else { u32::MAX << (32 - prefix) };
+    let start = network & mask;
+
+    (start as u128, (start | !mask) as u128)
+}
+
+#[inline]
+fn ipv6_block(network: u128, prefix: u8) -> (u128, u128) {
+    let mask = if prefix == 0 { 0 } else { u128::MAX << (128 - prefix) };
+    let start = network & mask;
+
+    (start, start | !mask)
+}
+
+/// Generate a random IPv4 address in a specific range with a new lazily-initialized thread-local random number generator.
+///
+/// `Ipv4Addr` can not implement `SampleUniform`, so this function takes the place of `random_ranged` for IPv4 addresses. The `random!` macro calls it for ranges of `Ipv4Addr`.
+///
+/// Panics if the start bound is exclusive or the range is empty.
+#[inline]
+pub fn random_ipv4_ranged<R: RangeBounds<Ipv4Addr>>(range: R) -> Ipv4Addr {
+    random_ipv4_ranged_with_rng(range, &mut default_rng())
+}
+
+/// Generate a random IPv4 address in a specific range with an existing random number generator.
+///
+/// Panics if the start bound is exclusive or the range is empty.
+///
+/// ## Examples
+///
+/// ```rust
+/// use std::net::{Ipv4Addr, Ipv6Addr};
+///
+/// use random_number::random;
+///
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// let start = Ipv4Addr::new(10, 0, 0, 0);
+/// let end = Ipv4Addr::new(10, 255, 255, 255);
+///
+/// let ip = random_number::random_ipv4_ranged_with_rng(start..=end, &mut rng);
+/// assert!((start..=end).contains(&ip));
+///
+/// // the same with the macro
+/// let ip: Ipv4Addr = random!(
+///     Ipv4Addr::new(10, 255, 255, 255),
+///     Ipv4Addr::new(10, 0, 0, 0),
+///     rng
+/// );
+/// assert!((start..=end).contains(&ip));
+///
+/// let ip: Ipv4Addr = random!(Ipv4Addr::new(224, 0, 0, 0)..);
+/// assert!(ip.octets()[0] >= 224);
+///
+/// let ip: Ipv6Addr =
+///     random!(Ipv6Addr::UNSPECIFIED..=Ipv6Addr::LOCALHOST, rng);
+/// assert!(ip.is_unspecified() || ip.is_loopback());
+///
+/// // the macro picks the function by the type of the bounds, however they are written
+/// type Address = Ipv4Addr;
+///
+/// fn multicast() -> Address {
+///     Address::new(224, 0, 0, 0)
+/// }
+///
+/// let ip = random!(start..end);
+/// assert!((start..end).contains(&ip));
+///
+/// let ip = random!(multicast()..=Address::BROADCAST, rng);
+/// assert!(ip >= multicast());
+///
+/// let ip = random!(..end);
+/// assert!(ip < end);
+/// ```
+#[inline]
+pub fn random_ipv4_ranged_with_rng<R: RangeBounds<Ipv4Addr>, T: Rng>(
+    range: R,
+    rng: &mut T,
+) -> Ipv4Addr {
+    match index_bounds(&range, "random_ipv4_ranged_with_rng") {
+        Some((start, end)) => Ipv4Addr::from(rng.gen_range(start as u32..=end as u32)),
+        None => panic!("random_ipv4_ranged_with_rng called with an empty range"),
+    }
+}
+
+/// Generate a random IPv6 address in a specific range with a new lazily-initialized thread-local random number generator.
+///
+/// `Ipv6Addr` can not implement `SampleUniform`, so this function takes the place of `random_ranged` for IPv6 addresses. The `random!` macro calls it for ranges of `Ipv6Addr`.
+///
+/// Panics if the start bound is exclusive or the range is empty.
+#[inline]
+pub fn random_ipv6_ranged<R: RangeBounds<Ipv6Addr>>(range: R) -> Ipv6Addr {
+    random_ipv6_ranged_with_rng(range, &mut default_rng())
+}
+
+/// Generate a random IPv6 address in a specific range with an existing random number generator.
+///
+/// Panics if the start bound is exclusive or the range is empty.
+#[inline]
+pub fn random_ipv6_ranged_with_rng<R: RangeBounds<Ipv6Addr>, T: Rng>(
+    range: R,
+    rng: &mut T,
+) -> Ipv6Addr {
+    match index_bounds(&range, "random_ipv6_ranged_with_rng") {
+        Some((start, end)) => Ipv6Addr::from(rng.gen_range(start..=end)),
+        None => panic!("random_ipv6_ranged_with_rng called with an empty range"),
+    }
+}
+
+fn parse_cidr(cidr: &str) -> Result<(IpAddr, u8), CidrError> {
+    let (addr, prefix) = match cidr.find('/') {
+        Some(index) => (&cidr[..index], &cidr[(index + 1)..]),
+        None => return Err(CidrError::InvalidFormat),
+    };
+
+    let addr: IpAddr = addr.trim().parse()?;
+    let prefix: u8 = prefix.trim().parse().map_err(|_| CidrError::InvalidPrefix)?;
+
+    let max_prefix = match addr {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    };
+
+    if prefix > max_prefix {
+        return Err(CidrError::InvalidPrefix);
+    }
+
+    Ok((addr, prefix))
+}
+
+/// Generate a random IP address in a CIDR block, such as `"192.168.0.0/16"` or `"2001:db8::/32"`, with a new lazily-initialized thread-local random number generator.
+#[inline]
+pub fn random_in_cidr(cidr: &str) -> Result<IpAddr, CidrError> {
+    random_in_cidr_with_rng(cidr, &mut default_rng())
+}
+
+/// Generate a random IP address in a CIDR block, such as `"192.168.0.0/16"` or `"2001:db8::/32"`, with an existing random number generator.
+#[inline]
+pub fn random_in_cidr_with_rng<T: Rng>(cidr: &str, rng: &mut T) -> Result<IpAddr, CidrError> {
+    random_in_cidr_excluding_with_rng(cidr, IpExclusion::default(), rng)
+}
+
+/// Generate a random IP address in a CIDR block except for the address ranges specified by `exclusion` with a new lazily-initialized thread-local random number generator.
+///
+/// ## Examples
+///
+/// ```rust
+/// use std::net::{IpAddr, Ipv4Addr};
+///
+/// use random_number::{CidrError, IpExclusion};
+///
+/// let exclusion = IpExclusion {
+///     network_and_broadcast: true,
+///     ..IpExclusion::default()
+/// };
+///
+/// for _ in 0..100 {
+///     let ip =
+///         random_number::random_in_cidr_excluding("10.1.2.0/30", exclusion)
+///             .unwrap();
+///
+///     assert!(
+///         ip == IpAddr::V4(Ipv4Addr::new(10, 1, 2, 1))
+///             || ip == IpAddr::V4(Ipv4Addr::new(10, 1, 2, 2))
+///     );
+/// }
+///
+/// let exclusion = IpExclusion {
+///     private: true,
+///     ..IpExclusion::default()
+/// };
+///
+/// assert_eq!(
+///     Err(CidrError::Empty),
+///     random_number::random_in_cidr_excluding("10.0.0.0/8", exclusion)
+/// );
+/// assert_eq!(
+///     Err(CidrError::InvalidPrefix),
+///     random_number::random_in_cidr("10.0.0.0/33")
+/// );
+/// ```
+#[inline]
+pub fn random_in_cidr_excluding(cidr: &str, exclusion: IpExclusion) -> Result<IpAddr, CidrError> {
+    random_in_cidr_excluding_with_rng(cidr, exclusion, &mut default_rng())
+}
+
+/// Generate a random IP address in a CIDR block except for the address ranges specified by `exclusion` with an existing random number generator.
+pub fn random_in_cidr_excluding_with_rng<T: Rng>(
+    cidr: &str,
+    exclusion: IpExclusion,
+    rng: &mut T,
+) -> Result<IpAddr, CidrError> {
+    let (addr, prefix) = parse_cidr(cidr)?;
+
+    let mut excluded = Vec::new();
+
+    match addr {
+        IpAddr::V4(addr) => {
+            let (start, end) = ipv4_block(u32::from(addr), prefix);
+
+            if exclusion.network_and_broadcast && prefix <= 30 {
+                excluded.push((start, start));
+                excluded.push((end, end));
+            }
+
+            if exclusion.private {
+                excluded.extend(IPV4_PRIVATE.iter().map(|&(n, p)| ipv4_block(n, p)));
+            }
+
+            if exclusion.reserved {
+                excluded.extend(IPV4_RESERVED.iter().map(|&(n, p)| ipv4_block(n, p)));
+            }
+
+            IndexSampler::new(start, end, excluded)
+                .map(|sampler| IpAddr::V4(Ipv4Addr::from(sampler.sample(rng) as u32)))
+                .ok_or(CidrError::Empty)
+        },
+        IpAddr::V6(addr) => {
+            let (start, end) = ipv6_block(u128::from(addr), prefix);
+
+            if exclusion.network_and_broadcast && prefix <= 126 {
+                excluded.push((start, start));
+            }
+
+            if exclusion.private {
+                excluded.extend(IPV6_PRIVATE.iter().map(|&(n, p)| ipv6_block(n, p)));
+            }
+
+            if exclusion.reserved {
+                excluded.extend(IPV6_RESERVED.iter().map(|&(n, p)| ipv6_block(n, p)));
+            }
+
+            IndexSampler::new(start, end, excluded)
+                .map(|sampler| IpAddr::V6(Ipv6Addr::from(sampler.sample(rng))))
+                .ok_or(CidrError::Empty)
+        },
+    }
+}
+
+/// Generate a random socket address in a CIDR block with a port in a specific range with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if the start bound of the port range is exclusive.
+#[inline]
+pub fn random_socket_addr<R: RangeBounds<u16>>(
+    cidr: &str,
+    port_range: R,
+) -> Result<SocketAddr, CidrError> {
+    random_socket_addr_with_rng(cidr, port_range, &mut default_rng())
+}
+
+/// Generate a random socket address in a CIDR block with a port in a specific range with an existing random number generator.
+///
+/// Panics if the start bound of the port range is exclusive.
+#[inline]
+pub fn random_socket_addr_with_rng<R: RangeBounds<u16>, T: Rng>(
+    cidr: &str,
+    port_range: R,
+    rng: &mut T,
+) -> Result<SocketAddr, CidrError> {
+    random_socket_addr_excluding_with_rng(cidr, port_range, IpExclusion::default(), rng)
+}
+
+/// Generate a random socket address in a CIDR block except for the address ranges specified by `exclusion` with a port in a specific range with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if the start bound of the port range is exclusive.
+#[inline]
+pub fn random_socket_addr_excluding<R: RangeBounds<u16>>(
+    cidr: &str,
+    port_range: R,
+    exclusion: IpExclusion,
+) -> Result<SocketAddr, CidrError> {
+    random_socket_addr_excluding_with_rng(cidr, port_range, exclusion, &mut default_rng())
+}
+
+/// Generate a random socket address in a CIDR block except for the address ranges specified by `exclusion` with a port in a specific range with an existing random number generator.
+///
+/// Panics if the start bound of the port range is exclusive.
+#[inline]
+pub fn random_socket_addr_excluding_with_rng<R: RangeBounds<u16>, T: Rng>(
+    cidr: &str,
+    port_range: R,
+    exclusion: IpExclusion,
+    rng: &mut T,
+) -> Result<SocketAddr, CidrError> {
+    let ip = random_in_cidr_excluding_with_rng(cidr, exclusion, rng)?;
+    let port = random_ranged_with_rng(port_range, rng);
+
+    Ok(SocketAddr::new(ip, port))
+}
+
+/// The ranges which have a start bound or an end bound, for the `random!` macro to pick a function by the type of the bounds.
+#[doc(hidden)]
+pub trait MacroRange {
+    type Bound;
+}
+
+/// The ranges of types implementing `SampleUniform`, which the `random!` macro passes to the function for each kind of range.
+#[doc(hidden)]
+pub trait MacroUniformRange<X> {
+    fn sample_with_rng<T: Rng>(self, rng: &mut T) -> X;
+}
+
+impl<B> MacroRange for Range<B> {
+    type Bound = B;
+}
+
+impl<X: SampleUniform, B: SampleBorrow<X>> MacroUniformRange<X> for Range<B> {
+    #[inline]
+    fn sample_with_rng<T: Rng>(self, rng: &mut T) -> X {
+        random_exclusively_with_rng(self.start, self.end, rng)
+    }
+}
+
+impl<B> MacroRange for RangeInclusive<B> {
+    type Bound = B;
+}
+
+impl<X: SampleUniform, B: SampleBorrow<X>> MacroUniformRange<X> for RangeInclusive<B> {
+    #[inline]
+    fn sample_with_rng<T: Rng>(self, rng: &mut T) -> X {
+        let (min, max) = self.into_inner();
+
+        random_inclusively_with_rng(min, max, rng)
+    }
+}
+
+impl<B> MacroRange for RangeFrom<B> {
+    type Bound = B;
+}
+
+impl<X: SampleUniform + Bounded, B: SampleBorrow<X>> MacroUniformRange<X> for RangeFrom<B> {
+    #[inline]
+    fn sample_with_rng<T: Rng>(self, rng: &mut T) -> X {
+        random_at_least_with_rng(self.start, rng)
+    }
+}
+
+impl<B> MacroRange for RangeTo<B> {
+    type Bound = B;
+}
+
+impl<X: SampleUniform + Bounded, B: SampleBorrow<X>> MacroUniformRange<X> for RangeTo<B> {
+    #[inline]
+    fn sample_with_rng<T: Rng>(self, rng: &mut T) -> X {
+        random_at_most_exclusively_with_rng(self.end, rng)
+    }
+}
+
+impl<B> MacroRange for RangeToInclusive<B> {
+    type Bound = B;
+}
+
+impl<X: SampleUniform + Bounded, B: SampleBorrow<X>> MacroUniformRange<X> for RangeToInclusive<B> {
+    #[inline]
+    fn sample_with_rng<T: Rng>(self, rng: &mut T) -> X {
+        random_at_most_with_rng(self.end, rng)
+    }
+}
+
+/// The type of the bounds of a range in the `random!` macro.
+///
+/// Calling `__random_number_sampler` on a reference to it picks the sampler of IPv4 or IPv6 addresses, which can not implement `SampleUniform`, for ranges of them, and the sampler of `SampleUniform` for any other range, because the methods of `MacroIpv4Dispatch` and `MacroIpv6Dispatch` are found before those of `MacroUniformDispatch`, which take one more reference.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct MacroRangeBound<B>(PhantomData<B>);
+
+#[doc(hidden)]
+#[inline]
+pub fn macro_range_bound<R: MacroRange>(_range: &R) -> MacroRangeBound<R::Bound> {
+    MacroRangeBound(PhantomData)
+}
+
+#[doc(hidden)]
+pub trait MacroIpv4Dispatch {
+    #[inline]
+    fn __random_number_sampler(&self) -> MacroIpv4Sampler {
+        MacroIpv4Sampler
+    }
+}
+
+impl MacroIpv4Dispatch for MacroRangeBound<Ipv4Addr> {}
+
+#[doc(hidden)]
+pub trait MacroIpv6Dispatch {
+    #[inline]
+    fn __random_number_sampler(&self) -> MacroIpv6Sampler {
+        MacroIpv6Sampler
+    }
+}
+
+impl MacroIpv6Dispatch for MacroRangeBound<Ipv6Addr> {}
+
+#[doc(hidden)]
+pub trait MacroUniformDispatch {
+    #[inline]
+    fn __random_number_sampler(&self) -> MacroUniformSampler {
+        MacroUniformSampler
+    }
+}
+
+impl<B> MacroUniformDispatch for &MacroRangeBound<B> {}
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct MacroIpv4Sampler;
+
+impl MacroIpv4Sampler {
+    #[inline]
+    pub fn sample_with_rng<R: RangeBounds<Ipv4Addr>, T: Rng>(self, range: R, rng: &mut T) -> Ipv4Addr {
+        random_ipv4_ranged_with_rng(range, rng)
+    }
+}
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct MacroIpv6Sampler;
+
+impl MacroIpv6Sampler {
+    #[inline]
+    pub fn sample_with_rng<R: RangeBounds<Ipv6Addr>, T: Rng>(self, range: R, rng: &mut T) -> Ipv6Addr {
+        random_ipv6_ranged_with_rng(range, rng)
+    }
+}
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct MacroUniformSampler;
+
+impl MacroUniformSampler {
+    #[inline]
+    pub fn sample_with_rng<X, R: MacroUniformRange<X>, T: Rng>(self, range: R, rng: &mut T) -> X {
+        range.sample_with_rng(rng)
+    }
+}
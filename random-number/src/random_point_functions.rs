@@ -0,0 +1,266 @@
+use crate::{default_rng, rand::Rng};
+
+/// Generate a standard normal variate by the Marsaglia polar method.
+#[inline]
+pub(crate) fn standard_normal_with_rng<T: Rng>(rng: &mut T) -> f64 {
+    loop {
+        let u = rng.gen::<f64>() * 2.0 - 1.0;
+        let v = rng.gen::<f64>() * 2.0 - 1.0;
+        let s = u * u + v * v;
+
+        if s > 0.0 && s < 1.0 {
+            return u * (-2.0 * s.ln() / s).sqrt();
+        }
+    }
+}
+
+/// Generate a random point in the box [`lo[i]`, `hi[i]`] of every dimension `i` with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if `lo[i] > hi[i]` for any dimension `i`.
+#[inline]
+pub fn random_point_in_box<const N: usize>(lo: [f64; N], hi: [f64; N]) -> [f64; N] {
+    random_point_in_box_with_rng(lo, hi, &mut default_rng())
+}
+
+/// Generate a random point in the box [`lo[i]`, `hi[i]`] of every dimension `i` with an existing random number generator.
+///
+/// Panics if `lo[i] > hi[i]` for any dimension `i`.
+#[inline]
+pub fn random_point_in_box_with_rng<const N: usize, T: Rng>(
+    lo: [f64; N],
+    hi: [f64; N],
+    rng: &mut T,
+) -> [f64; N] {
+    let mut point = [0f64; N];
+
+    for (i, x) in point.iter_mut().enumerate() {
+        *x = rng.gen_range(lo[i]..=hi[i]);
+    }
+
+    point
+}
+
+/// Generate random points in the box [`lo[i]`, `hi[i]`] of every dimension `i` with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if `lo[i] > hi[i]` for any dimension `i`.
+#[inline]
+pub fn random_fill_points_in_box<const N: usize>(out: &mut [[f64; N]], lo: [f64; N], hi: [f64; N]) {
+    random_fill_points_in_box_with_rng(out, lo, hi, &mut default_rng())
+}
+
+/// Generate random points in the box [`lo[i]`, `hi[i]`] of every dimension `i` with an existing random number generator.
+///
+/// Panics if `lo[i] > hi[i]` for any dimension `i`.
+#[inline]
+pub fn random_fill_points_in_box_with_rng<const N: usize, T: Rng>(
+    out: &mut [[f64; N]],
+    lo: [f64; N],
+    hi: [f64; N],
+    rng: &mut T,
+) {
+    for point in out.iter_mut() {
+        *point = random_point_in_box_with_rng(lo, hi, rng);
+    }
+}
+
+/// Generate a random point on the surface of the unit sphere centered at the origin with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if `N` is zero.
+#[inline]
+pub fn random_point_on_sphere<const N: usize>() -> [f64; N] {
+    random_point_on_sphere_with_rng(&mut default_rng())
+}
+
+/// Generate a random point on the surface of the unit sphere centered at the origin with an existing random number generator.
+///
+/// The point is a normalized vector of independent normal variates, so it is uniformly distributed on the sphere for any number of dimensions.
+///
+/// Panics if `N` is zero.
+pub fn random_point_on_sphere_with_rng<const N: usize, T: Rng>(rng: &mut T) -> [f64; N] {
+    if N == 0 {
+        panic!("random_point_on_sphere_with_rng called with zero dimensions");
+    }
+
+    let mut point = [0f64; N];
+
+    loop {
+        let mut norm = 0f64;
+
+        for x in point.iter_mut() {
+            *x = standard_normal_with_rng(rng);
+
+            norm += *x * *x;
+        }
+
+        if norm > 0.0 {
+            let norm = norm.sqrt();
+
+            for x in point.iter_mut() {
+                *x /= norm;
+            }
+
+            return point;
+        }
+    }
+}
+
+/// Generate random points on the surface of the unit sphere centered at the origin with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if `N` is zero.
+#[inline]
+pub fn random_fill_points_on_sphere<const N: usize>(out: &mut [[f64; N]]) {
+    random_fill_points_on_sphere_with_rng(out, &mut default_rng())
+}
+
+/// Generate random points on the surface of the unit sphere centered at the origin with an existing random number generator.
+///
+/// Panics if `N` is zero.
+#[inline]
+pub fn random_fill_points_on_sphere_with_rng<const N: usize, T: Rng>(
+    out: &mut [[f64; N]],
+    rng: &mut T,
+) {
+    for point in out.iter_mut() {
+        *point = random_point_on_sphere_with_rng(rng);
+    }
+}
+
+/// Generate a random point in the ball of `radius` centered at the origin with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if `N` is zero or `radius` is negative.
+#[inline]
+pub fn random_point_in_ball<const N: usize>(radius: f64) -> [f64; N] {
+    random_point_in_ball_with_rng(radius, &mut default_rng())
+}
+
+/// Generate a random point in the ball of `radius` centered at the origin with an existing random number generator.
+///
+/// Panics if `N` is zero or `radius` is negative.
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// for _ in 0..100 {
+///     let [x, y, z] =
+///         random_number::random_point_in_ball_with_rng(2.0, &mut rng);
+///
+///     assert!(x * x + y * y + z * z <= 4.0 + 1e-9);
+///
+///     let [x, y] = random_number::random_point_on_sphere_with_rng(&mut rng);
+///
+///     assert!((x * x + y * y - 1.0).abs() < 1e-9);
+/// }
+/// ```
+pub fn random_point_in_ball_with_rng<const N: usize, T: Rng>(radius: f64, rng: &mut T) -> [f64; N] {
+    if radius.is_nan() || radius < 0.0 {
+        panic!("random_point_in_ball_with_rng called with a negative radius");
+    }
+
+    let mut point = random_point_on_sphere_with_rng(rng);
+
+    // the volume within a radius `r` grows with `r^N`
+    let r = radius * rng.gen::<f64>().powf(1.0 / N as f64);
+
+    for x in point.iter_mut() {
+        *x *= r;
+    }
+
+    point
+}
+
+/// Generate random points in the ball of `radius` centered at the origin with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if `N` is zero or `radius` is negative.
+#[inline]
+pub fn random_fill_points_in_ball<const N: usize>(out: &mut [[f64; N]], radius: f64) {
+    random_fill_points_in_ball_with_rng(out, radius, &mut default_rng())
+}
+
+/// Generate random points in the ball of `radius` centered at the origin with an existing random number generator.
+///
+/// Panics if `N` is zero or `radius` is negative.
+#[inline]
+pub fn random_fill_points_in_ball_with_rng<const N: usize, T: Rng>(
+    out: &mut [[f64; N]],
+    radius: f64,
+    rng: &mut T,
+) {
+    for point in out.iter_mut() {
+        *point = random_point_in_ball_with_rng(radius, rng);
+    }
+}
+
+/// Generate a random point in the triangle whose vertices are `a`, `b` and `c` with a new lazily-initialized thread-local random number generator.
+#[inline]
+pub fn random_point_in_triangle<const N: usize>(a: [f64; N], b: [f64; N], c: [f64; N]) -> [f64; N] {
+    random_point_in_triangle_with_rng(a, b, c, &mut default_rng())
+}
+
+/// Generate a random point in the triangle whose vertices are `a`, `b` and `c` with an existing random number generator.
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// for _ in 0..100 {
+///     let [x, y] = random_number::random_point_in_triangle_with_rng(
+///         [0.0, 0.0],
+///         [1.0, 0.0],
+///         [0.0, 1.0],
+///         &mut rng,
+///     );
+///
+///     assert!(x >= 0.0 && y >= 0.0 && x + y <= 1.0 + 1e-9);
+/// }
+/// ```
+pub fn random_point_in_triangle_with_rng<const N: usize, T: Rng>(
+    a: [f64; N],
+    b: [f64; N],
+    c: [f64; N],
+    rng: &mut T,
+) -> [f64; N] {
+    let mut u = rng.gen::<f64>();
+    let mut v = rng.gen::<f64>();
+
+    // fold the other half of the parallelogram back into the triangle
+    if u + v > 1.0 {
+        u = 1.0 - u;
+        v = 1.0 - v;
+    }
+
+    let mut point = [0f64; N];
+
+    for (i, x) in point.iter_mut().enumerate() {
+        *x = a[i] + u * (b[i] - a[i]) + v * (c[i] - a[i]);
+    }
+
+    point
+}
+
+/// Generate random points in the triangle whose vertices are `a`, `b` and `c` with a new lazily-initialized thread-local random number generator.
+#[inline]
+pub fn random_fill_points_in_triangle<const N: usize>(
+    out: &mut [[f64; N]],
+    a: [f64; N],
+    b: [f64; N],
+    c: [f64; N],
+) {
+    random_fill_points_in_triangle_with_rng(out, a, b, c, &mut default_rng())
+}
+
+/// Generate random points in the triangle whose vertices are `a`, `b` and `c` with an existing random number generator.
+#[inline]
+pub fn random_fill_points_in_triangle_with_rng<const N: usize, T: Rng>(
+    out: &mut [[f64; N]],
+    a: [f64; N],
+    b: [f64; N],
+    c: [f64; N],
+    rng: &mut T,
+) {
+    for point in out.iter_mut() {
+        *point = random_point_in_triangle_with_rng(a, b, c, rng);
+    }
+}
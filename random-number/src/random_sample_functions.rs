@@ -0,0 +1,193 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use crate::{
+    default_rng,
+    rand::{distributions::Open01, Rng},
+    reservoir::{next_skip, next_w_factor},
+    WeightError,
+};
+
+/// Pick `k` items uniformly at random from an iterator of unknown length with a new lazily-initialized thread-local random number generator.
+///
+/// All items are returned if there are fewer than `k` items.
+#[inline]
+pub fn random_sample_iter<I: IntoIterator>(iter: I, k: usize) -> Vec<I::Item> {
+    random_sample_iter_with_rng(iter, k, &mut default_rng())
+}
+
+/// Pick `k` items uniformly at random from an iterator of unknown length with an existing random number generator.
+///
+/// This is Algorithm L of reservoir sampling, which skips the items that will not be kept by `Iterator::nth`, so it only draws O(k(1 + log(n/k))) random numbers. The items are returned in no particular order.
+///
+/// All items are returned if there are fewer than `k` items.
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// let log = "GET /\nGET /a\nPOST /b\nGET /c\nDELETE /d";
+///
+/// let lines =
+///     random_number::random_sample_iter_with_rng(log.lines(), 2, &mut rng);
+///
+/// assert_eq!(2, lines.len());
+///
+/// let lines =
+///     random_number::random_sample_iter_with_rng(log.lines(), 10, &mut rng);
+///
+/// assert_eq!(5, lines.len());
+/// ```
+pub fn random_sample_iter_with_rng<I: IntoIterator, T: Rng>(
+    iter: I,
+    k: usize,
+    rng: &mut T,
+) -> Vec<I::Item> {
+    let mut iter = iter.into_iter();
+
+    let mut reservoir: Vec<I::Item> = iter.by_ref().take(k).collect();
+
+    if reservoir.len() < k || k == 0 {
+        return reservoir;
+    }
+
+    let mut w = next_w_factor(k, rng);
+
+    loop {
+        let item = match iter.nth(next_skip(w, rng)) {
+            Some(item) => item,
+            None => break,
+        };
+
+        reservoir[rng.gen_range(0..k)] = item;
+
+        w *= next_w_factor(k, rng);
+    }
+
+    reservoir
+}
+
+/// An item with the logarithm of its key, ordered so that the smallest key is at the top of a `BinaryHeap`.
+struct Keyed<T> {
+    log_key: f64,
+    item:    T,
+}
+
+impl<T> PartialEq for Keyed<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.log_key == other.log_key
+    }
+}
+
+impl<T> Eq for Keyed<T> {}
+
+impl<T> PartialOrd for Keyed<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Keyed<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        // keys are never NaN
+        other.log_key.partial_cmp(&self.log_key).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Pick `k` items at random from an iterator of `(item, weight)` pairs of unknown length with a new lazily-initialized thread-local random number generator. Items are picked without replacement, with probabilities proportional to their weights.
+///
+/// Items whose weights are zero are never picked, so fewer than `k` items are returned if there are not enough items with positive weights.
+#[inline]
+pub fn random_sample_iter_weighted<T, I: IntoIterator<Item = (T, f64)>>(
+    iter: I,
+    k: usize,
+) -> Result<Vec<T>, WeightError> {
+    random_sample_iter_weighted_with_rng(iter, k, &mut default_rng())
+}
+
+/// Pick `k` items at random from an iterator of `(item, weight)` pairs of unknown length with an existing random number generator. Items are picked without replacement, with probabilities proportional to their weights.
+///
+/// This is Algorithm A-ExpJ of weighted reservoir sampling, which draws random numbers only when an item is kept. The items are returned in no particular order.
+///
+/// Items whose weights are zero are never picked, so fewer than `k` items are returned if there are not enough items with positive weights. An error is returned if a weight is negative, infinite or NaN.
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// let servers = vec![("a", 5.0), ("b", 0.0), ("c", 1.0), ("d", 0.0)];
+///
+/// let mut picked = random_number::random_sample_iter_weighted_with_rng(
+///     servers, 3, &mut rng,
+/// )
+/// .unwrap();
+///
+/// picked.sort_unstable();
+///
+/// assert_eq!(vec!["a", "c"], picked);
+/// ```
+pub fn random_sample_iter_weighted_with_rng<T, I: IntoIterator<Item = (T, f64)>, R: Rng>(
+    iter: I,
+    k: usize,
+    rng: &mut R,
+) -> Result<Vec<T>, WeightError> {
+    if k == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut heap: BinaryHeap<Keyed<T>> = BinaryHeap::with_capacity(k);
+
+    // the logarithm of a key `u^(1 / weight)` is `ln(u) / weight`, which does not underflow for tiny weights
+    let mut iter = iter.into_iter();
+
+    for (item, weight) in iter.by_ref() {
+        if !weight.is_finite() || weight < 0.0 {
+            return Err(WeightError::InvalidWeight);
+        }
+
+        if weight > 0.0 {
+            heap.push(Keyed {
+                log_key: rng.sample::<f64, _>(Open01).ln() / weight,
+                item,
+            });
+
+            if heap.len() == k {
+                break;
+            }
+        }
+    }
+
+    if heap.len() == k {
+        let mut threshold = heap.peek().unwrap().log_key;
+        let mut jump = rng.sample::<f64, _>(Open01).ln() / threshold;
+
+        for (item, weight) in iter {
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(WeightError::InvalidWeight);
+            }
+
+            jump -= weight;
+
+            if jump <= 0.0 {
+                // the new key is uniform in (threshold^weight, 1)
+                let t = (threshold * weight).exp();
+                let u = t + rng.sample::<f64, _>(Open01) * (1.0 - t);
+
+                heap.pop();
+                heap.push(Keyed {
+                    log_key: u.ln() / weight,
+                    item,
+                });
+
+                threshold = heap.peek().unwrap().log_key;
+                jump = rng.sample::<f64, _>(Open01).ln() / threshold;
+            }
+        }
+    }
+
+    Ok(heap.into_iter().map(|keyed| keyed.item).collect())
+}
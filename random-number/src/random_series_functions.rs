@@ -0,0 +1,723 @@
+use std::{
+    fmt::{self, Debug, Formatter},
+    iter::FusedIterator,
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{
+    default_rng,
+    rand::{
+        distributions::{
+            uniform::{SampleUniform, Uniform},
+            Distribution,
+        },
+        Rng,
+    },
+    random_point_functions::standard_normal_with_rng,
+    Bounded, DefaultRng,
+};
+
+/// How a random walk behaves at its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Boundary<X> {
+    /// The walk is not bounded, so integers overflow as with `+`.
+    Unbounded,
+    /// A value beyond the bounds `(min, max)` is replaced by the nearest bound.
+    Clamp(X, X),
+    /// A value beyond the bounds `(min, max)` is reflected back by the bounds, like a ball bouncing between two walls.
+    Reflect(X, X),
+}
+
+impl<X: Copy + PartialOrd> Boundary<X> {
+    /// Move a value beyond the bounds to the nearest bound.
+    #[inline]
+    fn clamp(&self, x: X) -> X {
+        match *self {
+            Boundary::Unbounded => x,
+            Boundary::Clamp(min, max) | Boundary::Reflect(min, max) => {
+                if x < min {
+                    min
+                } else if x > max {
+                    max
+                } else {
+                    x
+                }
+            },
+        }
+    }
+}
+
+/// Types whose values can take steps of a random walk within a `Boundary` without overflowing.
+///
+/// A reflected step which is longer than the span of the bounds bounces off both bounds as many times as needed, in constant time.
+///
+/// ## Examples
+///
+/// ```rust
+/// use random_number::{Boundary, Walkable};
+///
+/// assert_eq!(7, 3u8.walk(250, &Boundary::Reflect(0, 10)));
+/// assert_eq!(3, 2u8.walk(254, &Boundary::Clamp(0, 3)));
+/// assert_eq!(
+///     -100,
+///     (-100i128).walk(i128::MIN, &Boundary::Reflect(-100, -100))
+/// );
+///
+/// let x = 5u128.walk(u128::MAX, &Boundary::Reflect(5, u128::MAX - 5));
+/// assert!((5..=u128::MAX - 5).contains(&x));
+///
+/// let x = 1e15f64.walk(1e20, &Boundary::Reflect(1e15, 1e15 + 1.0));
+/// assert!((1e15..=1e15 + 1.0).contains(&x));
+///
+/// let x = 0.5f32.walk(-1e30, &Boundary::Reflect(0.0, 1.0));
+/// assert!((0.0..=1.0).contains(&x));
+///
+/// assert_eq!(0.75, 0.5f64.walk(4.25, &Boundary::Reflect(0.0, 1.0)));
+/// ```
+pub trait Walkable: Copy + PartialOrd {
+    /// Move `self`, which is within the bounds, by `step`, and keep the result within the bounds.
+    fn walk(self, step: Self, boundary: &Boundary<Self>) -> Self;
+}
+
+macro_rules! walkable_integer_impl {
+    ($t:ident, $u:ident) => {
+        impl Walkable for $t {
+            #[inline]
+            fn walk(self, step: Self, boundary: &Boundary<Self>) -> Self {
+                let (min, max, reflect) = match *boundary {
+                    Boundary::Unbounded => return self + step,
+                    Boundary::Clamp(min, max) => (min, max, false),
+                    Boundary::Reflect(min, max) => (min, max, true),
+                };
+
+                if max <= min {
+                    return min;
+                }
+
+                // the offset from `min`, the span and the length of the step fit in the unsigned type
+                let span = (max as $u).wrapping_sub(min as $u);
+                let offset = (self as $u).wrapping_sub(min as $u);
+
+                #[allow(unused_comparisons)]
+                let (up, length) = if step >= 0 {
+                    (true, step as $u)
+                } else {
+                    (false, (step as $u).wrapping_neg())
+                };
+
+                let room = if up { span - offset } else { offset };
+
+                let offset = if length <= room {
+                    if up {
+                        offset + length
+                    } else {
+                        offset - length
+                    }
+                } else if !reflect {
+                    if up {
+                        span
+                    } else {
+                        0
+                    }
+                } else {
+                    // the overshoot beyond the bound, folded as a triangle wave whose period is `2 * span`
+                    let overshoot = match span.checked_mul(2) {
+                        Some(period) => (length - room) % period,
+                        None => length - room,
+                    };
+
+                    let bounced =
+                        if overshoot <= span { overshoot } else { span - (overshoot - span) };
+
+                    if up {
+                        span - bounced
+                    } else {
+                        bounced
+                    }
+                };
+
+                (min as $u).wrapping_add(offset) as $t
+            }
+        }
+    };
+}
+
+walkable_integer_impl!(u8, u8);
+walkable_integer_impl!(u16, u16);
+walkable_integer_impl!(u32, u32);
+walkable_integer_impl!(u64, u64);
+walkable_integer_impl!(u128, u128);
+walkable_integer_impl!(usize, usize);
+walkable_integer_impl!(i8, u8);
+walkable_integer_impl!(i16, u16);
+walkable_integer_impl!(i32, u32);
+walkable_integer_impl!(i64, u64);
+walkable_integer_impl!(i128, u128);
+walkable_integer_impl!(isize, usize);
+
+macro_rules! walkable_float_impl {
+    ($t:ident) => {
+        impl Walkable for $t {
+            #[inline]
+            fn walk(self, step: Self, boundary: &Boundary<Self>) -> Self {
+                let mut x = self + step;
+
+                match *boundary {
+                    Boundary::Unbounded => x,
+                    Boundary::Clamp(min, max) => x.max(min).min(max),
+                    Boundary::Reflect(min, max) => {
+                        if max <= min {
+                            return min;
+                        }
+
+                        let span = max - min;
+
+                        // the overshoot beyond the bound, folded as a triangle wave whose period is `2 * span`
+                        let (overshoot, up) = if x > max {
+                            ((x - max) % (span * 2.0), true)
+                        } else if x < min {
+                            ((min - x) % (span * 2.0), false)
+                        } else {
+                            return x;
+                        };
+
+                        let bounced =
+                            if overshoot <= span { overshoot } else { span - (overshoot - span) };
+
+                        x = if up { max - bounced } else { min + bounced };
+
+                        // rounding may leave the bounds by a little
+                        x.max(min).min(max)
+                    },
+                }
+            }
+        }
+    };
+}
+
+walkable_float_impl!(f32);
+walkable_float_impl!(f64);
+
+fn uniform_from_range<X: SampleUniform + Bounded, R: RangeBounds<X>>(
+    range: R,
+    caller: &str,
+) -> Uniform<X> {
+    match range.start_bound() {
+        Bound::Excluded(_) => panic!("{} called with a start bound which is exclusive", caller),
+        Bound::Included(min) => match range.end_bound() {
+            Bound::Excluded(max_exclusive) => Uniform::new(min, max_exclusive),
+            Bound::Included(max_inclusive) => Uniform::new_inclusive(min, max_inclusive),
+            Bound::Unbounded => Uniform::new_inclusive(min, X::max_value()),
+        },
+        Bound::Unbounded => match range.end_bound() {
+            Bound::Excluded(max_exclusive) => Uniform::new(X::min_value(), max_exclusive),
+            Bound::Included(max_inclusive) => Uniform::new_inclusive(X::min_value(), max_inclusive),
+            Bound::Unbounded => Uniform::new_inclusive(X::min_value(), X::max_value()),
+        },
+    }
+}
+
+/// An endless iterator of a random walk whose steps are uniformly distributed.
+pub struct RandomWalk<X: SampleUniform, R: Rng> {
+    current:  X,
+    step:     Uniform<X>,
+    boundary: Boundary<X>,
+    rng:      R,
+}
+
+// `Uniform<X>` is only `Debug` if its sampler is
+impl<X: SampleUniform + Debug, R: Rng + Debug> Debug for RandomWalk<X, R>
+where
+    X::Sampler: Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RandomWalk")
+            .field("current", &self.current)
+            .field("step", &self.step)
+            .field("boundary", &self.boundary)
+            .field("rng", &self.rng)
+            .finish()
+    }
+}
+
+impl<X: SampleUniform + Walkable, R: Rng> RandomWalk<X, R> {
+    /// Keep the walk within bounds. A start value beyond the bounds is moved to the nearest bound.
+    #[inline]
+    pub fn bounded(mut self, boundary: Boundary<X>) -> Self {
+        self.current = boundary.clamp(self.current);
+        self.boundary = boundary;
+
+        self
+    }
+}
+
+impl<X: SampleUniform + Walkable, R: Rng> Iterator for RandomWalk<X, R> {
+    type Item = X;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = self.current;
+
+        self.current = x.walk(self.step.sample(&mut self.rng), &self.boundary);
+
+        Some(x)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<X: SampleUniform + Walkable, R: Rng> FusedIterator for RandomWalk<X, R> {}
+
+/// Create an endless iterator of a random walk which starts from `start` and moves by a step uniformly distributed in `step_range` each time, with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if the start bound of `step_range` is exclusive or `step_range` is empty.
+#[inline]
+pub fn random_walk<X: SampleUniform + Bounded + Walkable, S: RangeBounds<X>>(
+    start: X,
+    step_range: S,
+) -> RandomWalk<X, DefaultRng> {
+    RandomWalk {
+        current:  start,
+        step:     uniform_from_range(step_range, "random_walk"),
+        boundary: Boundary::Unbounded,
+        rng:      default_rng(),
+    }
+}
+
+/// Create an endless iterator of a random walk which starts from `start` and moves by a step uniformly distributed in `step_range` each time, with an existing random number generator.
+///
+/// Panics if the start bound of `step_range` is exclusive or `step_range` is empty.
+///
+/// ## Examples
+///
+/// ```rust
+/// use random_number::Boundary;
+///
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// let walk: Vec<i32> =
+///     random_number::random_walk_with_rng(50, -5..=5, &mut rng)
+///         .bounded(Boundary::Reflect(0, 100))
+///         .take(1000)
+///         .collect();
+///
+/// assert_eq!(50, walk[0]);
+/// assert!(walk.windows(2).all(|w| (w[0] - w[1]).abs() <= 5));
+/// assert!(walk.iter().all(|x| (0..=100).contains(x)));
+///
+/// // steps near the end of the type do not overflow
+/// let walk: Vec<u8> =
+///     random_number::random_walk_with_rng(250, 0..=10, &mut rng)
+///         .bounded(Boundary::Clamp(0, 255))
+///         .take(100)
+///         .collect();
+///
+/// assert_eq!(255, walk[99]);
+/// ```
+#[inline]
+pub fn random_walk_with_rng<X: SampleUniform + Bounded + Walkable, S: RangeBounds<X>, T: Rng>(
+    start: X,
+    step_range: S,
+    rng: &mut T,
+) -> RandomWalk<X, &mut T> {
+    RandomWalk {
+        current: start,
+        step: uniform_from_range(step_range, "random_walk_with_rng"),
+        boundary: Boundary::Unbounded,
+        rng,
+    }
+}
+
+/// Fill a slice with a random walk which starts from `start` and moves by a step uniformly distributed in `step_range` each time, with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if the start bound of `step_range` is exclusive or `step_range` is empty.
+#[inline]
+pub fn random_walk_fill<X: SampleUniform + Bounded + Walkable, S: RangeBounds<X>>(
+    out: &mut [X],
+    start: X,
+    step_range: S,
+) {
+    random_walk_fill_bounded_with_rng(
+        out,
+        start,
+        step_range,
+        Boundary::Unbounded,
+        &mut default_rng(),
+    )
+}
+
+/// Fill a slice with a random walk which starts from `start` and moves by a step uniformly distributed in `step_range` each time, with an existing random number generator.
+///
+/// Panics if the start bound of `step_range` is exclusive or `step_range` is empty.
+#[inline]
+pub fn random_walk_fill_with_rng<
+    X: SampleUniform + Bounded + Walkable,
+    S: RangeBounds<X>,
+    T: Rng,
+>(
+    out: &mut [X],
+    start: X,
+    step_range: S,
+    rng: &mut T,
+) {
+    random_walk_fill_bounded_with_rng(out, start, step_range, Boundary::Unbounded, rng)
+}
+
+/// Fill a slice with a random walk which starts from `start`, moves by a step uniformly distributed in `step_range` each time and is kept within bounds, with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if the start bound of `step_range` is exclusive or `step_range` is empty.
+#[inline]
+pub fn random_walk_fill_bounded<X: SampleUniform + Bounded + Walkable, S: RangeBounds<X>>(
+    out: &mut [X],
+    start: X,
+    step_range: S,
+    boundary: Boundary<X>,
+) {
+    random_walk_fill_bounded_with_rng(out, start, step_range, boundary, &mut default_rng())
+}
+
+/// Fill a slice with a random walk which starts from `start`, moves by a step uniformly distributed in `step_range` each time and is kept within bounds, with an existing random number generator.
+///
+/// Panics if the start bound of `step_range` is exclusive or `step_range` is empty.
+///
+/// ## Examples
+///
+/// ```rust
+/// use random_number::Boundary;
+///
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// let mut cpu = [0f64; 1000];
+/// random_number::random_walk_fill_bounded_with_rng(
+///     &mut cpu,
+///     30.0,
+///     -2.0..=2.0,
+///     Boundary::Clamp(0.0, 100.0),
+///     &mut rng,
+/// );
+///
+/// assert!(cpu.iter().all(|x| (0.0..=100.0).contains(x)));
+/// ```
+pub fn random_walk_fill_bounded_with_rng<
+    X: SampleUniform + Bounded + Walkable,
+    S: RangeBounds<X>,
+    T: Rng,
+>(
+    out: &mut [X],
+    start: X,
+    step_range: S,
+    boundary: Boundary<X>,
+    rng: &mut T,
+) {
+    let walk = RandomWalk {
+        current: boundary.clamp(start),
+        step: uniform_from_range(step_range, "random_walk_fill_bounded_with_rng"),
+        boundary,
+        rng,
+    };
+
+    for (x, y) in out.iter_mut().zip(walk) {
+        *x = y;
+    }
+}
+
+/// An endless iterator of a geometric Brownian motion, sampled at a fixed time step.
+#[derive(Debug)]
+pub struct GeometricBrownianMotion<R: Rng> {
+    current:    f64,
+    drift:      f64,
+    volatility: f64,
+    rng:        R,
+}
+
+impl<R: Rng> GeometricBrownianMotion<R> {
+    #[inline]
+    fn new(start: f64, drift: f64, volatility: f64, dt: f64, rng: R) -> Self {
+        if dt.is_nan() || dt < 0.0 {
+            panic!("a geometric Brownian motion needs a non-negative time step");
+        }
+
+        if volatility.is_nan() || volatility < 0.0 {
+            panic!("a geometric Brownian motion needs a non-negative volatility");
+        }
+
+        // the exact solution over one step is `s * exp((mu - sigma^2 / 2) dt + sigma sqrt(dt) z)`
+        GeometricBrownianMotion {
+            current: start,
+            drift: (drift - volatility * volatility / 2.0) * dt,
+            volatility: volatility * dt.sqrt(),
+            rng,
+        }
+    }
+}
+
+impl<R: Rng> Iterator for GeometricBrownianMotion<R> {
+    type Item = f64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = self.current;
+
+        self.current =
+            x * (self.drift + self.volatility * standard_normal_with_rng(&mut self.rng)).exp();
+
+        Some(x)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<R: Rng> FusedIterator for GeometricBrownianMotion<R> {}
+
+/// Create an endless iterator of a geometric Brownian motion which starts from `start`, with the annualized `drift` and `volatility` and the time step `dt` (in years), with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if `volatility` or `dt` is negative.
+#[inline]
+pub fn random_geometric_brownian_motion(
+    start: f64,
+    drift: f64,
+    volatility: f64,
+    dt: f64,
+) -> GeometricBrownianMotion<DefaultRng> {
+    GeometricBrownianMotion::new(start, drift, volatility, dt, default_rng())
+}
+
+/// Create an endless iterator of a geometric Brownian motion which starts from `start`, with the annualized `drift` and `volatility` and the time step `dt` (in years), with an existing random number generator.
+///
+/// The values stay positive if `start` is positive, which makes them suitable for prices.
+///
+/// Panics if `volatility` or `dt` is negative.
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// let prices: Vec<f64> =
+///     random_number::random_geometric_brownian_motion_with_rng(
+///         100.0,
+///         0.05,
+///         0.2,
+///         1.0 / 252.0,
+///         &mut rng,
+///     )
+///     .take(252)
+///     .collect();
+///
+/// assert_eq!(100.0, prices[0]);
+/// assert!(prices.iter().all(|&p| p > 0.0));
+/// ```
+#[inline]
+pub fn random_geometric_brownian_motion_with_rng<T: Rng>(
+    start: f64,
+    drift: f64,
+    volatility: f64,
+    dt: f64,
+    rng: &mut T,
+) -> GeometricBrownianMotion<&mut T> {
+    GeometricBrownianMotion::new(start, drift, volatility, dt, rng)
+}
+
+/// Fill a slice with a geometric Brownian motion which starts from `start`, with the annualized `drift` and `volatility` and the time step `dt` (in years), with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if `volatility` or `dt` is negative.
+#[inline]
+pub fn random_geometric_brownian_motion_fill(
+    out: &mut [f64],
+    start: f64,
+    drift: f64,
+    volatility: f64,
+    dt: f64,
+) {
+    random_geometric_brownian_motion_fill_with_rng(
+        out,
+        start,
+        drift,
+        volatility,
+        dt,
+        &mut default_rng(),
+    )
+}
+
+/// Fill a slice with a geometric Brownian motion which starts from `start`, with the annualized `drift` and `volatility` and the time step `dt` (in years), with an existing random number generator.
+///
+/// Panics if `volatility` or `dt` is negative.
+#[inline]
+pub fn random_geometric_brownian_motion_fill_with_rng<T: Rng>(
+    out: &mut [f64],
+    start: f64,
+    drift: f64,
+    volatility: f64,
+    dt: f64,
+    rng: &mut T,
+) {
+    let process = GeometricBrownianMotion::new(start, drift, volatility, dt, rng);
+
+    for (x, y) in out.iter_mut().zip(process) {
+        *x = y;
+    }
+}
+
+/// An endless iterator of an Ornstein–Uhlenbeck process, sampled at a fixed time step.
+#[derive(Debug)]
+pub struct OrnsteinUhlenbeck<R: Rng> {
+    current: f64,
+    mean:    f64,
+    decay:   f64,
+    noise:   f64,
+    rng:     R,
+}
+
+impl<R: Rng> OrnsteinUhlenbeck<R> {
+    #[inline]
+    fn new(start: f64, mean: f64, reversion: f64, volatility: f64, dt: f64, rng: R) -> Self {
+        if dt.is_nan() || dt < 0.0 {
+            panic!("an Ornstein–Uhlenbeck process needs a non-negative time step");
+        }
+
+        if reversion.is_nan() || reversion < 0.0 {
+            panic!("an Ornstein–Uhlenbeck process needs a non-negative reversion rate");
+        }
+
+        if volatility.is_nan() || volatility < 0.0 {
+            panic!("an Ornstein–Uhlenbeck process needs a non-negative volatility");
+        }
+
+        // the exact solution over one step, which degrades to Brownian motion without reversion
+        let decay = (-reversion * dt).exp();
+
+        let noise = if reversion > 0.0 {
+            volatility * (-(-2.0 * reversion * dt).exp_m1() / (2.0 * reversion)).sqrt()
+        } else {
+            volatility * dt.sqrt()
+        };
+
+        OrnsteinUhlenbeck {
+            current: start,
+            mean,
+            decay,
+            noise,
+            rng,
+        }
+    }
+}
+
+impl<R: Rng> Iterator for OrnsteinUhlenbeck<R> {
+    type Item = f64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = self.current;
+
+        self.current = self.mean
+            + (x - self.mean) * self.decay
+            + self.noise * standard_normal_with_rng(&mut self.rng);
+
+        Some(x)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<R: Rng> FusedIterator for OrnsteinUhlenbeck<R> {}
+
+/// Create an endless iterator of an Ornstein–Uhlenbeck process which starts from `start` and is pulled towards `mean` at the rate `reversion`, with the `volatility` and the time step `dt`, with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if `reversion`, `volatility` or `dt` is negative.
+#[inline]
+pub fn random_ornstein_uhlenbeck(
+    start: f64,
+    mean: f64,
+    reversion: f64,
+    volatility: f64,
+    dt: f64,
+) -> OrnsteinUhlenbeck<DefaultRng> {
+    OrnsteinUhlenbeck::new(start, mean, reversion, volatility, dt, default_rng())
+}
+
+/// Create an endless iterator of an Ornstein–Uhlenbeck process which starts from `start` and is pulled towards `mean` at the rate `reversion`, with the `volatility` and the time step `dt`, with an existing random number generator.
+///
+/// This fits metrics which fluctuate around a typical level, such as latencies or temperatures.
+///
+/// Panics if `reversion`, `volatility` or `dt` is negative.
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// let latencies: Vec<f64> =
+///     random_number::random_ornstein_uhlenbeck_with_rng(
+///         500.0, 50.0, 1.0, 0.0, 1.0, &mut rng,
+///     )
+///     .take(100)
+///     .collect();
+///
+/// // without volatility, the process decays towards the mean
+/// assert!(latencies.windows(2).all(|w| w[1] <= w[0]));
+/// assert!((latencies[99] - 50.0).abs() < 1e-9);
+/// ```
+#[inline]
+pub fn random_ornstein_uhlenbeck_with_rng<T: Rng>(
+    start: f64,
+    mean: f64,
+    reversion: f64,
+    volatility: f64,
+    dt: f64,
+    rng: &mut T,
+) -> OrnsteinUhlenbeck<&mut T> {
+    OrnsteinUhlenbeck::new(start, mean, reversion, volatility, dt, rng)
+}
+
+/// Fill a slice with an Ornstein–Uhlenbeck process which starts from `start` and is pulled towards `mean` at the rate `reversion`, with the `volatility` and the time step `dt`, with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if `reversion`, `volatility` or `dt` is negative.
+#[inline]
+pub fn random_ornstein_uhlenbeck_fill(
+    out: &mut [f64],
+    start: f64,
+    mean: f64,
+    reversion: f64,
+    volatility: f64,
+    dt: f64,
+) {
+    random_ornstein_uhlenbeck_fill_with_rng(
+        out,
+        start,
+        mean,
+        reversion,
+        volatility,
+        dt,
+        &mut default_rng(),
+    )
+}
+
+/// Fill a slice with an Ornstein–Uhlenbeck process which starts from `start` and is pulled towards `mean` at the rate `reversion`, with the `volatility` and the time step `dt`, with an existing random number generator.
+///
+/// Panics if `reversion`, `volatility` or `dt` is negative.
+#[inline]
+pub fn random_ornstein_uhlenbeck_fill_with_rng<T: Rng>(
+    out: &mut [f64],
+    start: f64,
+    mean: f64,
+    reversion: f64,
+    volatility: f64,
+    dt: f64,
+    rng: &mut T,
+) {
+    let process = OrnsteinUhlenbeck::new(start, mean, reversion, volatility, dt, rng);
+
+    for (x, y) in out.iter_mut().zip(process) {
+        *x = y;
+    }
+}
@@ -0,0 +1,257 @@
+use std::ops::{Bound, RangeBounds};
+
+use crate::{
+    default_rng, rand::Rng, random_except_functions::index_bounds,
+    random_unique_functions::random_unique_indices_with_rng, Bounded, Discrete,
+    NotEnoughValuesError,
+};
+
+/// Types whose random values can be generated directly in ascending order.
+pub trait SampleSorted: Sized {
+    /// Fill a slice with random values in a specific range, sorted in ascending order.
+    ///
+    /// Panics if the start bound is exclusive or the range is empty.
+    fn fill_sorted_with_rng<R: RangeBounds<Self>, T: Rng>(out: &mut [Self], range: R, rng: &mut T);
+}
+
+/// Sort indices in the range [`start`, `end`] in expected linear time by distributing them into `indices.len()` equal-width buckets.
+pub(crate) fn bucket_sort_indices(indices: &mut [u128], start: u128, end: u128) {
+    let n = indices.len();
+
+    if n < 2 {
+        return;
+    }
+
+    let width = (end - start) / n as u128 + 1;
+    let bucket = |index: u128| ((index - start) / width) as usize;
+
+    let mut offsets = vec![0usize; n + 1];
+
+    for &index in indices.iter() {
+        offsets[bucket(index) + 1] += 1;
+    }
+
+    for i in 1..=n {
+        offsets[i] += offsets[i - 1];
+    }
+
+    let mut sorted = vec![0u128; n];
+    let mut next = offsets.clone();
+
+    for &index in indices.iter() {
+        let b = bucket(index);
+
+        sorted[next[b]] = index;
+        next[b] += 1;
+    }
+
+    for b in 0..n {
+        sorted[offsets[b]..offsets[b + 1]].sort_unstable();
+    }
+
+    indices.copy_from_slice(&sorted);
+}
+
+fn fill_sorted_discrete_with_rng<X: Discrete, R: RangeBounds<X>, T: Rng>(
+    out: &mut [X],
+    range: R,
+    rng: &mut T,
+) {
+    let (start, end) = match index_bounds(&range, "random_fill_sorted_with_rng") {
+        Some(bounds) => bounds,
+        None => panic!("random_fill_sorted_with_rng called with an empty range"),
+    };
+
+    let mut indices: Vec<u128> = (0..out.len()).map(|_| rng.gen_range(start..=end)).collect();
+
+    bucket_sort_indices(&mut indices, start, end);
+
+    for (x, index) in out.iter_mut().zip(indices) {
+        *x = X::from_index(index);
+    }
+}
+
+macro_rules! sample_sorted_discrete_impl {
+    ($t:ident) => {
+        impl SampleSorted for $t {
+            #[inline]
+            fn fill_sorted_with_rng<R: RangeBounds<Self>, T: Rng>(
+                out: &mut [Self],
+                range: R,
+                rng: &mut T,
+            ) {
+                fill_sorted_discrete_with_rng(out, range, rng)
+            }
+        }
+    };
+}
+
+sample_sorted_discrete_impl!(u8);
+sample_sorted_discrete_impl!(u16);
+sample_sorted_discrete_impl!(u32);
+sample_sorted_discrete_impl!(u64);
+sample_sorted_discrete_impl!(u128);
+sample_sorted_discrete_impl!(usize);
+sample_sorted_discrete_impl!(i8);
+sample_sorted_discrete_impl!(i16);
+sample_sorted_discrete_impl!(i32);
+sample_sorted_discrete_impl!(i64);
+sample_sorted_discrete_impl!(i128);
+sample_sorted_discrete_impl!(isize);
+
+macro_rules! sample_sorted_float_impl {
+    ($t:ident) => {
+        impl SampleSorted for $t {
+            /// The samples are the order statistics obtained from the normalized partial sums of `out.len() + 1` exponential spacings.
+            fn fill_sorted_with_rng<R: RangeBounds<Self>, T: Rng>(
+                out: &mut [Self],
+                range: R,
+                rng: &mut T,
+            ) {
+                let min = match range.start_bound() {
+                    Bound::Excluded(_) => panic!(
+                        "random_fill_sorted_with_rng called with a start bound which is exclusive"
+                    ),
+                    Bound::Included(min) => *min,
+                    Bound::Unbounded => <$t as Bounded>::min_value(),
+                };
+
+                let (max, exclusive) = match range.end_bound() {
+                    Bound::Excluded(max_exclusive) => (*max_exclusive, true),
+                    Bound::Included(max_inclusive) => (*max_inclusive, false),
+                    Bound::Unbounded => (<$t as Bounded>::max_value(), false),
+                };
+
+                if !(min < max || (!exclusive && min == max)) {
+                    panic!("random_fill_sorted_with_rng called with an empty range");
+                }
+
+                let mut total = 0f64;
+
+                for x in out.iter_mut() {
+                    total -= (1.0 - rng.gen::<f64>()).ln();
+
+                    *x = total as $t;
+                }
+
+                total -= (1.0 - rng.gen::<f64>()).ln();
+
+                let span = max as f64 - min as f64;
+                let mut previous = min;
+
+                for x in out.iter_mut() {
+                    let mut y = (min as f64 + (*x as f64 / total) * span) as $t;
+
+                    if y > max || (exclusive && y == max) {
+                        y = previous;
+                    }
+
+                    *x = y;
+                    previous = y;
+                }
+            }
+        }
+    };
+}
+
+sample_sorted_float_impl!(f32);
+sample_sorted_float_impl!(f64);
+
+/// Generate random values in a specific range, sorted in ascending order, with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if the start bound is exclusive or the range is empty.
+#[inline]
+pub fn random_fill_sorted<X: SampleSorted, R: RangeBounds<X>>(out: &mut [X], range: R) {
+    random_fill_sorted_with_rng(out, range, &mut default_rng())
+}
+
+/// Generate random values in a specific range, sorted in ascending order, with an existing random number generator.
+///
+/// This takes expected linear time. Integers are distributed into equal-width buckets, and floats are built from exponential spacings.
+///
+/// Panics if the start bound is exclusive or the range is empty.
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// let mut a = [0u64; 100];
+/// random_number::random_fill_sorted_with_rng(&mut a, 1_000..2_000, &mut rng);
+///
+/// assert!(a.windows(2).all(|w| w[0] <= w[1]));
+/// assert!(1_000 <= a[0] && a[99] < 2_000);
+///
+/// let mut f = [0f64; 100];
+/// random_number::random_fill_sorted_with_rng(&mut f, -1.0..1.0, &mut rng);
+///
+/// assert!(f.windows(2).all(|w| w[0] <= w[1]));
+/// assert!(-1.0 <= f[0] && f[99] < 1.0);
+/// ```
+#[inline]
+pub fn random_fill_sorted_with_rng<X: SampleSorted, R: RangeBounds<X>, T: Rng>(
+    out: &mut [X],
+    range: R,
+    rng: &mut T,
+) {
+    X::fill_sorted_with_rng(out, range, rng)
+}
+
+/// Generate distinct random values in a specific range, sorted in ascending order, with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if the start bound is exclusive.
+#[inline]
+pub fn random_fill_sorted_unique<X: Discrete, R: RangeBounds<X>>(
+    out: &mut [X],
+    range: R,
+) -> Result<(), NotEnoughValuesError> {
+    random_fill_sorted_unique_with_rng(out, range, &mut default_rng())
+}
+
+/// Generate distinct random values in a specific range, sorted in ascending order, with an existing random number generator.
+///
+/// Panics if the start bound is exclusive.
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// let mut a = [0i32; 100];
+/// random_number::random_fill_sorted_unique_with_rng(
+///     &mut a,
+///     -100..100,
+///     &mut rng,
+/// )
+/// .unwrap();
+///
+/// assert!(a.windows(2).all(|w| w[0] < w[1]));
+/// ```
+pub fn random_fill_sorted_unique_with_rng<X: Discrete, R: RangeBounds<X>, T: Rng>(
+    out: &mut [X],
+    range: R,
+    rng: &mut T,
+) -> Result<(), NotEnoughValuesError> {
+    if out.is_empty() {
+        return Ok(());
+    }
+
+    let (start, end) = match index_bounds(&range, "random_fill_sorted_unique_with_rng") {
+        Some(bounds) => bounds,
+        None => {
+            return Err(NotEnoughValuesError {
+                requested: out.len(), available: 0
+            })
+        },
+    };
+
+    let mut indices = random_unique_indices_with_rng(out.len(), start, end, rng)?;
+
+    bucket_sort_indices(&mut indices, start, end);
+
+    for (x, index) in out.iter_mut().zip(indices) {
+        *x = X::from_index(index);
+    }
+
+    Ok(())
+}
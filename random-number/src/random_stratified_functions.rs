@@ -0,0 +1,141 @@
+use std::ops::RangeBounds;
+
+use crate::{
+    default_rng,
+    quasi::{unit_map_bounds, UnitMappable},
+    rand::{seq::SliceRandom, Rng},
+};
+
+/// Generate random values in a range which is divided into `out.len()` strata of equal width, with one value uniformly jittered in each stratum, with a new lazily-initialized thread-local random number generator.
+///
+/// An unbounded start or end is 0 or 1.
+///
+/// Panics if the range is empty or the start bound is exclusive.
+#[inline]
+pub fn random_fill_stratified<R: RangeBounds<f64>>(out: &mut [f64], range: R) {
+    random_fill_stratified_with_rng(out, range, &mut default_rng())
+}
+
+/// Generate random values in a range which is divided into `out.len()` strata of equal width, with one value uniformly jittered in each stratum, with an existing random number generator.
+///
+/// `out[i]` is in the stratum `i`, so the values are in ascending order. Unlike `random_fill_ranged`, the values never cluster, even for small sample counts.
+///
+/// An unbounded start or end is 0 or 1.
+///
+/// Panics if the range is empty or the start bound is exclusive.
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// let mut a = [0f64; 5];
+/// random_number::random_fill_stratified_with_rng(&mut a, 0.0..10.0, &mut rng);
+///
+/// for (i, x) in a.iter().enumerate() {
+///     assert!((i as f64 * 2.0..(i + 1) as f64 * 2.0).contains(x));
+/// }
+///
+/// // an exclusive end is never reached, even by rounding
+/// let mut rng = random_number::rand::rngs::mock::StepRng::new(u64::MAX, 0);
+///
+/// let mut a = [0f64; 3];
+/// random_number::random_fill_stratified_with_rng(&mut a, 1.0..2.0, &mut rng);
+///
+/// assert!(a[2] < 2.0);
+/// ```
+pub fn random_fill_stratified_with_rng<R: RangeBounds<f64>, T: Rng>(
+    out: &mut [f64],
+    range: R,
+    rng: &mut T,
+) {
+    let (min, max, exclusive) = unit_map_bounds(&range, "random_fill_stratified_with_rng");
+
+    let n = out.len() as f64;
+
+    for (i, x) in out.iter_mut().enumerate() {
+        *x = f64::unit_map(min, max, exclusive, (i as f64 + rng.gen::<f64>()) / n);
+    }
+}
+
+/// Generate `n` points of a Latin hypercube sample in `dims` dimensions with a new lazily-initialized thread-local random number generator.
+///
+/// An unbounded start or end is 0 or 1.
+///
+/// Panics if the length of `ranges` is not `dims`, or any range is empty or has an exclusive start bound.
+#[inline]
+pub fn latin_hypercube<R: RangeBounds<f64>>(n: usize, dims: usize, ranges: &[R]) -> Vec<Vec<f64>> {
+    latin_hypercube_with_rng(n, dims, ranges, &mut default_rng())
+}
+
+/// Generate `n` points of a Latin hypercube sample in `dims` dimensions with an existing random number generator.
+///
+/// The range of each dimension, `ranges[d]`, is divided into `n` strata of equal width, and every stratum of every dimension holds exactly one point, jittered uniformly within it. The strata are paired across dimensions by random permutations.
+///
+/// An unbounded start or end is 0 or 1.
+///
+/// Panics if the length of `ranges` is not `dims`, or any range is empty or has an exclusive start bound.
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// // a sweep of 10 settings of two parameters
+/// let points = random_number::latin_hypercube_with_rng(
+///     10,
+///     2,
+///     &[0.0..1.0, 0.0..10.0],
+///     &mut rng,
+/// );
+///
+/// assert_eq!(10, points.len());
+///
+/// // every stratum of every dimension holds exactly one point
+/// let mut strata: Vec<usize> =
+///     points.iter().map(|p| (p[0] * 10.0) as usize).collect();
+/// strata.sort_unstable();
+///
+/// assert_eq!((0..10).collect::<Vec<usize>>(), strata);
+///
+/// let mut strata: Vec<usize> = points.iter().map(|p| p[1] as usize).collect();
+/// strata.sort_unstable();
+///
+/// assert_eq!((0..10).collect::<Vec<usize>>(), strata);
+///
+/// // an exclusive end is never reached, even by rounding
+/// let mut rng = random_number::rand::rngs::mock::StepRng::new(u64::MAX, 0);
+///
+/// let points =
+///     random_number::latin_hypercube_with_rng(1, 1, &[1.0..2.0], &mut rng);
+///
+/// assert!(points.iter().all(|p| p[0] < 2.0));
+/// ```
+pub fn latin_hypercube_with_rng<R: RangeBounds<f64>, T: Rng>(
+    n: usize,
+    dims: usize,
+    ranges: &[R],
+    rng: &mut T,
+) -> Vec<Vec<f64>> {
+    if ranges.len() != dims {
+        panic!("latin_hypercube_with_rng called with a number of ranges which is not dims");
+    }
+
+    let mut points: Vec<Vec<f64>> = (0..n).map(|_| Vec::with_capacity(dims)).collect();
+
+    let mut strata: Vec<usize> = (0..n).collect();
+
+    for range in ranges {
+        let (min, max, exclusive) = unit_map_bounds(range, "latin_hypercube_with_rng");
+
+        strata.shuffle(rng);
+
+        for (point, &stratum) in points.iter_mut().zip(strata.iter()) {
+            let u = (stratum as f64 + rng.gen::<f64>()) / n as f64;
+
+            point.push(f64::unit_map(min, max, exclusive, u));
+        }
+    }
+
+    points
+}
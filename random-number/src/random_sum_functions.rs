@@ -0,0 +1,583 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{
+    default_rng, rand::Rng, random_sorted_functions::bucket_sort_indices,
+    random_unique_functions::random_unique_indices_with_rng,
+};
+
+/// The error of requesting values with a fixed sum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SumError {
+    /// The sum can not be reached by values in the range.
+    Infeasible,
+    /// The sum can be reached, but rounding keeps moving floats out of the range.
+    Intractable,
+}
+
+impl Display for SumError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SumError::Infeasible => {
+                f.write_str("the sum can not be reached by values in the range")
+            },
+            SumError::Intractable => {
+                f.write_str("rounding keeps moving the values with the sum out of the range")
+            },
+        }
+    }
+}
+
+impl Error for SumError {}
+
+/// Types whose random values can be generated with a fixed sum.
+pub trait SampleSum: Copy + PartialOrd {
+    /// The default lower bound of every value.
+    const ZERO: Self;
+
+    /// Fill a slice with random values in the range [`min`, `max`] whose sum is `total`, uniformly over all such slices.
+    fn fill_with_sum_with_rng<T: Rng>(
+        out: &mut [Self],
+        total: Self,
+        min: Self,
+        max: Bound<Self>,
+        rng: &mut T,
+    ) -> Result<(), SumError>;
+}
+
+/// Split `s` into `n` non-negative parts, uniformly over all compositions, by the stars and bars method.
+fn random_composition_with_rng<T: Rng>(n: usize, s: u128, rng: &mut T) -> Vec<u128> {
+    if n == 1 {
+        return vec![s];
+    }
+
+    let last = s + (n as u128 - 2);
+
+    let mut bars = random_unique_indices_with_rng(n - 1, 0, last, rng).unwrap();
+
+    bucket_sort_indices(&mut bars, 0, last);
+
+    let mut parts = Vec::with_capacity(n);
+    let mut previous = 0;
+
+    for (i, &bar) in bars.iter().enumerate() {
+        parts.push(if i == 0 { bar } else { bar - previous - 1 });
+
+        previous = bar;
+    }
+
+    parts.push(last - previous);
+
+    parts
+}
+
+/// Find the tilt `λ` <= 0 for which the mean of a part in [0, `w`] whose density is proportional to e<sup>`λx`</sup> is about `m`, which is in [0, `w` / 2]. `mean_of` gives the mean for a negative `λ`.
+///
+/// The tilt only affects how often the bounded samplers reject, not the distribution of their results, so an approximation is enough.
+fn solve_tilt<F: Fn(f64) -> f64>(m: f64, w: f64, mean_of: F) -> f64 {
+    if m * 2.0 >= w {
+        return 0.0;
+    }
+
+    let mut low = -1f64;
+
+    while mean_of(low) >= m {
+        low *= 2.0;
+    }
+
+    let mut high = 0f64;
+
+    for _ in 0..64 {
+        let middle = low / 2.0 + high / 2.0;
+
+        if mean_of(middle) < m {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    low / 2.0 + high / 2.0
+}
+
+/// Split `s` into `n` parts in the range [0, `w`], uniformly over all such compositions. `s` must not be greater than `n * w`.
+///
+/// The first `n - 1` parts are independent, each with a probability proportional to e<sup>`λx`</sup>, and the last one is the rest of `s`. Accepting the result with a probability of e<sup>`λ` * last</sup> makes every composition equally likely whatever `λ` is, and with `λ` chosen to make the mean part `s / n`, about one in O(sqrt(`n`)) results is accepted.
+fn random_bounded_composition_with_rng<T: Rng>(
+    n: usize,
+    s: u128,
+    w: u128,
+    rng: &mut T,
+) -> Vec<u128> {
+    if n == 1 {
+        return vec![s];
+    }
+
+    // the complement of a composition is also a composition, so sample the one whose mean part is not above `w / 2`
+    let flip = s * 2 > w * n as u128;
+    let s = if flip { w * n as u128 - s } else { s };
+
+    let width = w as f64 + 1.0;
+
+    // the mean of `x` in [0, `w`] with a probability proportional to e^(λx)
+    let lambda = solve_tilt(s as f64 / n as f64, w as f64, |lambda| {
+        1.0 / (-lambda).exp_m1() - width / (-lambda * width).exp_m1()
+    });
+
+    let mut parts = Vec::with_capacity(n);
+
+    'sample: loop {
+        parts.clear();
+
+        let mut sum = 0u128;
+
+        for _ in 1..n {
+            let x = if lambda == 0.0 {
+                rng.gen_range(0..=w)
+            } else {
+                // the floor of `y` in [0, `w` + 1) with a density proportional to e^(λy)
+                let y = (rng.gen::<f64>() * (lambda * width).exp_m1()).ln_1p() / lambda;
+
+                let mut x = y as u128;
+
+                // fill the bits which are below the precision of `y`
+                let exponent = ((y.to_bits() >> 52) & 0x7FF) as i32 - 1075;
+
+                if exponent > 0 {
+                    x += rng.gen_range(0..1u128 << exponent);
+                }
+
+                x.min(w)
+            };
+
+            parts.push(x);
+            sum += x;
+
+            if sum > s {
+                continue 'sample;
+            }
+        }
+
+        let last = s - sum;
+
+        if last <= w && (lambda == 0.0 || rng.gen::<f64>() < (lambda * last as f64).exp()) {
+            parts.push(last);
+
+            break;
+        }
+    }
+
+    if flip {
+        for part in parts.iter_mut() {
+            *part = w - *part;
+        }
+    }
+
+    parts
+}
+
+macro_rules! sample_sum_int_impl {
+    ($t:ident) => {
+        impl SampleSum for $t {
+            const ZERO: Self = 0;
+
+            fn fill_with_sum_with_rng<T: Rng>(
+                out: &mut [Self],
+                total: Self,
+                min: Self,
+                max: Bound<Self>,
+                rng: &mut T,
+            ) -> Result<(), SumError> {
+                let n = out.len();
+
+                if n == 0 {
+                    return if total == 0 { Ok(()) } else { Err(SumError::Infeasible) };
+                }
+
+                let min = min as i128;
+
+                // values must not exceed the type even if the range has no end
+                let max = match max {
+                    Bound::Excluded(max_exclusive) => max_exclusive as i128 - 1,
+                    Bound::Included(max_inclusive) => max_inclusive as i128,
+                    Bound::Unbounded => $t::MAX as i128,
+                };
+
+                let s = total as i128 - n as i128 * min;
+                let w = max - min;
+
+                if s < 0 || w < 0 || (n as i128).checked_mul(w).map_or(false, |m| s > m) {
+                    return Err(SumError::Infeasible);
+                }
+
+                let parts = if w >= s {
+                    random_composition_with_rng(n, s as u128, rng)
+                } else {
+                    random_bounded_composition_with_rng(n, s as u128, w as u128, rng)
+                };
+
+                for (x, part) in out.iter_mut().zip(parts) {
+                    *x = (min + part as i128) as $t;
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
+
+sample_sum_int_impl!(u8);
+sample_sum_int_impl!(u16);
+sample_sum_int_impl!(u32);
+sample_sum_int_impl!(u64);
+sample_sum_int_impl!(usize);
+sample_sum_int_impl!(i8);
+sample_sum_int_impl!(i16);
+sample_sum_int_impl!(i32);
+sample_sum_int_impl!(i64);
+sample_sum_int_impl!(isize);
+
+/// Split `s` into `out.len()` non-negative parts, uniformly over the simplex, from normalized exponential spacings.
+fn random_simplex_with_rng<T: Rng>(out: &mut [f64], s: f64, rng: &mut T) {
+    let mut sum = 0f64;
+
+    for x in out.iter_mut() {
+        *x = -(1.0 - rng.gen::<f64>()).ln();
+
+        sum += *x;
+    }
+
+    if sum > 0.0 {
+        for x in out.iter_mut() {
+            *x = *x / sum * s;
+        }
+    } else {
+        let share = s / out.len() as f64;
+
+        for x in out.iter_mut() {
+            *x = share;
+        }
+    }
+}
+
+/// Split `s` into `n` parts in the range [0, `w`], uniformly over that slice of the simplex, in the same way as `random_bounded_composition_with_rng`. `s` must not be greater than `n * w`.
+fn random_bounded_simplex_with_rng<T: Rng>(n: usize, s: f64, w: f64, rng: &mut T) -> Vec<f64> {
+    if n == 1 {
+        return vec![s];
+    }
+
+    let flip = s * 2.0 > w * n as f64;
+    let s = if flip { w * n as f64 - s } else { s };
+
+    // the mean of `x` in [0, `w`] with a density proportional to e^(λx)
+    let lambda = solve_tilt(s / n as f64, w, |lambda| -1.0 / lambda - w / (-lambda * w).exp_m1());
+
+    let mut parts = Vec::with_capacity(n);
+
+    'sample: loop {
+        parts.clear();
+
+        let mut sum = 0f64;
+
+        for _ in 1..n {
+            let x = if lambda == 0.0 {
+                rng.gen::<f64>() * w
+            } else {
+                (rng.gen::<f64>() * (lambda * w).exp_m1()).ln_1p() / lambda
+            };
+
+            let x = x.min(w);
+
+            parts.push(x);
+            sum += x;
+
+            if sum > s {
+                continue 'sample;
+            }
+        }
+
+        let last = s - sum;
+
+        if last <= w && (lambda == 0.0 || rng.gen::<f64>() < (lambda * last).exp()) {
+            parts.push(last);
+
+            break;
+        }
+    }
+
+    if flip {
+        for part in parts.iter_mut() {
+            *part = w - *part;
+        }
+    }
+
+    parts
+}
+
+/// The maximum number of samples of floats with an upper bound which are rejected because rounding moves a value out of the range, before giving up.
+const FLOAT_SUM_ATTEMPTS: usize = 1000;
+
+macro_rules! sample_sum_float_impl {
+    ($t:ident) => {
+        impl SampleSum for $t {
+            const ZERO: Self = 0.0;
+
+            fn fill_with_sum_with_rng<T: Rng>(
+                out: &mut [Self],
+                total: Self,
+                min: Self,
+                max: Bound<Self>,
+                rng: &mut T,
+            ) -> Result<(), SumError> {
+                let n = out.len();
+
+                if n == 0 {
+                    return if total == 0.0 { Ok(()) } else { Err(SumError::Infeasible) };
+                }
+
+                let low = min as f64;
+                let s = total as f64 - n as f64 * low;
+
+                if s.is_nan() || s < 0.0 {
+                    return Err(SumError::Infeasible);
+                }
+
+                let mut parts = vec![0f64; n];
+
+                let (max, excluded) = match max {
+                    Bound::Excluded(max) => (max, true),
+                    Bound::Included(max) => (max, false),
+                    Bound::Unbounded => {
+                        random_simplex_with_rng(&mut parts, s, rng);
+
+                        for (x, part) in out.iter_mut().zip(parts) {
+                            *x = (low + part) as $t;
+                        }
+
+                        return Ok(());
+                    },
+                };
+
+                let w = max as f64 - low;
+
+                if w.is_nan() || w < 0.0 || s > n as f64 * w || (excluded && s >= n as f64 * w) {
+                    return Err(SumError::Infeasible);
+                }
+
+                if w >= s {
+                    random_simplex_with_rng(&mut parts, s, rng);
+
+                    for (x, part) in out.iter_mut().zip(parts) {
+                        *x = (low + part) as $t;
+                    }
+
+                    if out.iter().all(|&x| if excluded { x < max } else { x <= max }) {
+                        return Ok(());
+                    }
+                }
+
+                // only rounding can move the values out of the range
+                for _ in 0..FLOAT_SUM_ATTEMPTS {
+                    let parts = random_bounded_simplex_with_rng(n, s, w, rng);
+
+                    for (x, part) in out.iter_mut().zip(parts) {
+                        *x = (low + part) as $t;
+                    }
+
+                    if out.iter().all(|&x| x >= min && if excluded { x < max } else { x <= max }) {
+                        return Ok(());
+                    }
+                }
+
+                Err(SumError::Intractable)
+            }
+        }
+    };
+}
+
+sample_sum_float_impl!(f32);
+sample_sum_float_impl!(f64);
+
+/// Generate non-negative random values whose sum is `total` with a new lazily-initialized thread-local random number generator.
+#[inline]
+pub fn random_fill_with_sum<X: SampleSum>(out: &mut [X], total: X) -> Result<(), SumError> {
+    random_fill_with_sum_with_rng(out, total, &mut default_rng())
+}
+
+/// Generate non-negative random values whose sum is `total` with an existing random number generator.
+///
+/// The values are uniformly distributed over all the compositions of `total` for integers, or over the simplex for floats, whose sum is exact up to rounding.
+///
+/// Returns `SumError::Infeasible` if `total` is negative.
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// let mut a = [0u32; 10];
+/// random_number::random_fill_with_sum_with_rng(&mut a, 1000, &mut rng)
+///     .unwrap();
+///
+/// assert_eq!(1000, a.iter().sum::<u32>());
+///
+/// let mut f = [0f64; 10];
+/// random_number::random_fill_with_sum_with_rng(&mut f, 1.0, &mut rng)
+///     .unwrap();
+///
+/// assert!((f.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+/// assert!(f.iter().all(|&f| f >= 0.0));
+/// ```
+#[inline]
+pub fn random_fill_with_sum_with_rng<X: SampleSum, T: Rng>(
+    out: &mut [X],
+    total: X,
+    rng: &mut T,
+) -> Result<(), SumError> {
+    X::fill_with_sum_with_rng(out, total, X::ZERO, Bound::Unbounded, rng)
+}
+
+/// Generate random values in a specific range whose sum is `total` with a new lazily-initialized thread-local random number generator.
+///
+/// An unbounded start is treated as zero. Panics if the start bound is exclusive.
+#[inline]
+pub fn random_fill_with_sum_ranged<X: SampleSum, R: RangeBounds<X>>(
+    out: &mut [X],
+    total: X,
+    range: R,
+) -> Result<(), SumError> {
+    random_fill_with_sum_ranged_with_rng(out, total, range, &mut default_rng())
+}
+
+/// Generate random values in a specific range whose sum is `total` with an existing random number generator.
+///
+/// An unbounded start is treated as zero. Panics if the start bound is exclusive.
+///
+/// Returns `SumError::Infeasible` if `total` can not be reached by values in the range. Integers are always bounded by their type, even if the range has no end.
+///
+/// The values are uniformly distributed over all the compositions of `total` in the range for integers, or over that slice of the simplex for floats. When the upper bound can be reached, they are sampled by rejection in expected O(`out.len()`<sup>1.5</sup>) time. `SumError::Intractable` is returned only if rounding keeps moving floats out of the range, which happens when the range is too narrow for the precision of the type.
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// let mut a = [0u16; 8];
+/// random_number::random_fill_with_sum_ranged_with_rng(
+///     &mut a,
+///     100,
+///     10..=15,
+///     &mut rng,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(100, a.iter().sum::<u16>());
+/// assert!(a.iter().all(|&a| (10..=15).contains(&a)));
+///
+/// let mut f = [0f32; 4];
+/// random_number::random_fill_with_sum_ranged_with_rng(
+///     &mut f,
+///     2.0,
+///     0.25..=0.75,
+///     &mut rng,
+/// )
+/// .unwrap();
+///
+/// assert!(f.iter().all(|&f| 0.25 <= f && f <= 0.75));
+///
+/// assert_eq!(
+///     Err(random_number::SumError::Infeasible),
+///     random_number::random_fill_with_sum_ranged_with_rng(
+///         &mut a,
+///         200,
+///         10..=15,
+///         &mut rng
+///     )
+/// );
+///
+/// // the values are still bounded by `i8::MAX`
+/// let mut b = [0i8; 3];
+/// random_number::random_fill_with_sum_ranged_with_rng(
+///     &mut b,
+///     100,
+///     -100..,
+///     &mut rng,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(100, b.iter().map(|&b| b as i32).sum::<i32>());
+/// assert!(b.iter().all(|&b| b >= -100));
+///
+/// // quotas whose bounds are reached by many values
+/// let mut c = [0u32; 100];
+/// random_number::random_fill_with_sum_ranged_with_rng(
+///     &mut c,
+///     10000,
+///     0..=200,
+///     &mut rng,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(10000, c.iter().sum::<u32>());
+/// assert!(c.iter().all(|&c| c <= 200));
+///
+/// let mut d = [0u8; 1000];
+/// random_number::random_fill_with_sum_ranged_with_rng(
+///     &mut d,
+///     100,
+///     0..=1,
+///     &mut rng,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(100, d.iter().filter(|&&d| d == 1).count());
+///
+/// let mut g = [0f64; 100];
+/// random_number::random_fill_with_sum_ranged_with_rng(
+///     &mut g,
+///     50.0,
+///     0.0..=1.0,
+///     &mut rng,
+/// )
+/// .unwrap();
+///
+/// assert!((g.iter().sum::<f64>() - 50.0).abs() < 1e-9);
+/// assert!(g.iter().all(|&g| (0.0..=1.0).contains(&g)));
+///
+/// let mut h = [0f32; 1000];
+/// random_number::random_fill_with_sum_ranged_with_rng(
+///     &mut h,
+///     999.0,
+///     0.0..1.0,
+///     &mut rng,
+/// )
+/// .unwrap();
+///
+/// assert!(h.iter().all(|&h| (0.0..1.0).contains(&h)));
+/// ```
+pub fn random_fill_with_sum_ranged_with_rng<X: SampleSum, R: RangeBounds<X>, T: Rng>(
+    out: &mut [X],
+    total: X,
+    range: R,
+    rng: &mut T,
+) -> Result<(), SumError> {
+    let min = match range.start_bound() {
+        Bound::Excluded(_) => panic!(
+            "random_fill_with_sum_ranged_with_rng called with a start bound which is exclusive"
+        ),
+        Bound::Included(min) => *min,
+        Bound::Unbounded => X::ZERO,
+    };
+
+    let max = match range.end_bound() {
+        Bound::Excluded(max_exclusive) => Bound::Excluded(*max_exclusive),
+        Bound::Included(max_inclusive) => Bound::Included(*max_inclusive),
+        Bound::Unbounded => Bound::Unbounded,
+    };
+
+    X::fill_with_sum_with_rng(out, total, min, max, rng)
+}
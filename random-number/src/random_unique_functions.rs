@@ -0,0 +1,184 @@
+use std::{
+    collections::HashSet,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    ops::RangeBounds,
+};
+
+use crate::{
+    default_rng,
+    rand::{seq::SliceRandom, Rng},
+    random_except_functions::index_bounds,
+    Discrete,
+};
+
+/// The error of requesting more distinct values than a range contains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NotEnoughValuesError {
+    /// The number of requested distinct values.
+    pub requested: usize,
+    /// The number of values in the range.
+    pub available: u128,
+}
+
+impl Display for NotEnoughValuesError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} distinct values are requested but the range only contains {} values",
+            self.requested, self.available
+        )
+    }
+}
+
+impl Error for NotEnoughValuesError {}
+
+/// Pick `n` distinct indices in the range [`start`, `end`] in random order.
+pub(crate) fn random_unique_indices_with_rng<T: Rng>(
+    n: usize,
+    start: u128,
+    end: u128,
+    rng: &mut T,
+) -> Result<Vec<u128>, NotEnoughValuesError> {
+    // `None` means the full `u128` range, which can never be too small
+    let count = (end - start).checked_add(1);
+
+    if let Some(count) = count {
+        if count < n as u128 {
+            return Err(NotEnoughValuesError {
+                requested: n, available: count
+            });
+        }
+
+        // partial Fisher–Yates shuffle when at least half of the range is requested
+        if count <= (n as u128).saturating_mul(2) {
+            let mut indices: Vec<u128> = (0..count as usize).map(|i| start + i as u128).collect();
+
+            let (chosen, _) = indices.partial_shuffle(rng, n);
+
+            return Ok(chosen.to_vec());
+        }
+    }
+
+    // Floyd's algorithm for sparse samples
+    let mut chosen: HashSet<u128> = HashSet::with_capacity(n);
+    let mut indices = Vec::with_capacity(n);
+
+    let last = end - start;
+
+    for j in (last - (n as u128 - 1))..=last {
+        let t = rng.gen_range(0..=j);
+
+        let index = if chosen.insert(t) {
+            t
+        } else {
+            chosen.insert(j);
+
+            j
+        };
+
+        indices.push(start + index);
+    }
+
+    indices.shuffle(rng);
+
+    Ok(indices)
+}
+
+/// Generate distinct random values in a specific range with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if the start bound is exclusive.
+#[inline]
+pub fn random_fill_unique<X: Discrete, R: RangeBounds<X>>(
+    out: &mut [X],
+    range: R,
+) -> Result<(), NotEnoughValuesError> {
+    random_fill_unique_with_rng(out, range, &mut default_rng())
+}
+
+/// Generate distinct random values in a specific range with an existing random number generator.
+///
+/// Partial Fisher–Yates shuffling is used when `out` covers at least half of the range, and Floyd's algorithm otherwise.
+///
+/// Panics if the start bound is exclusive.
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// let mut a = [0u8; 10];
+/// random_number::random_fill_unique_with_rng(&mut a, 1..=10, &mut rng)
+///     .unwrap();
+///
+/// a.sort_unstable();
+/// assert_eq!([1, 2, 3, 4, 5, 6, 7, 8, 9, 10], a);
+///
+/// let mut a = [0i64; 100];
+/// random_number::random_fill_unique_with_rng(&mut a, .., &mut rng).unwrap();
+///
+/// a.sort_unstable();
+/// assert!(a.windows(2).all(|w| w[0] < w[1]));
+///
+/// let mut a = [0u8; 11];
+/// assert!(random_number::random_fill_unique_with_rng(
+///     &mut a,
+///     1..=10,
+///     &mut rng
+/// )
+/// .is_err());
+/// ```
+pub fn random_fill_unique_with_rng<X: Discrete, R: RangeBounds<X>, T: Rng>(
+    out: &mut [X],
+    range: R,
+    rng: &mut T,
+) -> Result<(), NotEnoughValuesError> {
+    if out.is_empty() {
+        return Ok(());
+    }
+
+    let (start, end) = match index_bounds(&range, "random_fill_unique_with_rng") {
+        Some(bounds) => bounds,
+        None => {
+            return Err(NotEnoughValuesError {
+                requested: out.len(), available: 0
+            })
+        },
+    };
+
+    let indices = random_unique_indices_with_rng(out.len(), start, end, rng)?;
+
+    for (x, index) in out.iter_mut().zip(indices) {
+        *x = X::from_index(index);
+    }
+
+    Ok(())
+}
+
+/// Generate a vector of `n` distinct random values in a specific range with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if the start bound is exclusive.
+#[inline]
+pub fn random_unique_vec<X: Discrete, R: RangeBounds<X>>(
+    n: usize,
+    range: R,
+) -> Result<Vec<X>, NotEnoughValuesError> {
+    random_unique_vec_with_rng(n, range, &mut default_rng())
+}
+
+/// Generate a vector of `n` distinct random values in a specific range with an existing random number generator.
+///
+/// Panics if the start bound is exclusive.
+#[inline]
+pub fn random_unique_vec_with_rng<X: Discrete, R: RangeBounds<X>, T: Rng>(
+    n: usize,
+    range: R,
+    rng: &mut T,
+) -> Result<Vec<X>, NotEnoughValuesError> {
+    let mut out = vec![X::min_value(); n];
+
+    random_fill_unique_with_rng(&mut out, range, rng)?;
+
+    Ok(out)
+}
@@ -0,0 +1,73 @@
+use std::ops::RangeBounds;
+
+use crate::{default_rng, rand::Rng, AliasTable, Discrete};
+
+#[inline]
+fn alias_table(weights: &[f64], caller: &str) -> AliasTable {
+    match AliasTable::new(weights) {
+        Ok(table) => table,
+        Err(error) => panic!("{} called with invalid weights: {}", caller, error),
+    }
+}
+
+/// Generate a random value in a specific range, where the `i`-th value of the range has the `i`-th weight, with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if the start bound is exclusive, the weights are invalid, or the range does not contain exactly `weights.len()` values.
+#[inline]
+pub fn random_weighted_ranged<X: Discrete, R: RangeBounds<X>>(range: R, weights: &[f64]) -> X {
+    random_weighted_ranged_with_rng(range, weights, &mut default_rng())
+}
+
+/// Generate a random value in a specific range, where the `i`-th value of the range has the `i`-th weight, with an existing random number generator.
+///
+/// Panics if the start bound is exclusive, the weights are invalid, or the range does not contain exactly `weights.len()` values.
+///
+/// ## Examples
+///
+/// ```rust
+/// let mut rng = random_number::rand::thread_rng();
+///
+/// for _ in 0..100 {
+///     let n: u8 = random_number::random_weighted_ranged_with_rng(
+///         1..=6,
+///         &[1.0, 0.0, 1.0, 0.0, 1.0, 5.0],
+///         &mut rng,
+///     );
+///
+///     assert!(n == 1 || n == 3 || n == 5 || n == 6);
+/// }
+/// ```
+#[inline]
+pub fn random_weighted_ranged_with_rng<X: Discrete, R: RangeBounds<X>, T: Rng>(
+    range: R,
+    weights: &[f64],
+    rng: &mut T,
+) -> X {
+    alias_table(weights, "random_weighted_ranged_with_rng").sample_ranged_with_rng(range, rng)
+}
+
+/// Generate random values in a specific range, where the `i`-th value of the range has the `i`-th weight, with a new lazily-initialized thread-local random number generator.
+///
+/// Panics if the start bound is exclusive, the weights are invalid, or the range does not contain exactly `weights.len()` values.
+#[inline]
+pub fn random_fill_weighted_ranged<X: Discrete, R: RangeBounds<X>>(
+    out: &mut [X],
+    range: R,
+    weights: &[f64],
+) {
+    random_fill_weighted_ranged_with_rng(out, range, weights, &mut default_rng())
+}
+
+/// Generate random values in a specific range, where the `i`-th value of the range has the `i`-th weight, with an existing random number generator.
+///
+/// Panics if the start bound is exclusive, the weights are invalid, or the range does not contain exactly `weights.len()` values.
+#[inline]
+pub fn random_fill_weighted_ranged_with_rng<X: Discrete, R: RangeBounds<X>, T: Rng>(
+    out: &mut [X],
+    range: R,
+    weights: &[f64],
+    rng: &mut T,
+) {
+    alias_table(weights, "random_fill_weighted_ranged_with_rng")
+        .fill_ranged_with_rng(out, range, rng)
+}
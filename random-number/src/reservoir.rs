@@ -0,0 +1,131 @@
+use crate::{
+    default_rng,
+    rand::{distributions::Open01, Rng},
+};
+
+/// Draw the multiplier of the threshold `w` of Algorithm L.
+#[inline]
+pub(crate) fn next_w_factor<T: Rng>(k: usize, rng: &mut T) -> f64 {
+    (rng.sample::<f64, _>(Open01).ln() / k as f64).exp()
+}
+
+/// Draw the number of items to skip before the next replacement of Algorithm L.
+#[inline]
+pub(crate) fn next_skip<T: Rng>(w: f64, rng: &mut T) -> usize {
+    // a float-to-integer cast saturates, so an infinite skip becomes `usize::MAX`
+    (rng.sample::<f64, _>(Open01).ln() / (-w).ln_1p()).floor() as usize
+}
+
+/// A reservoir which keeps `k` items chosen uniformly at random from a stream of unknown length, fed one item at a time.
+///
+/// The items to be skipped are computed in advance by Algorithm L, so random numbers are only drawn when an item is kept.
+#[derive(Debug, Clone)]
+pub struct Reservoir<T> {
+    k:     usize,
+    items: Vec<T>,
+    seen:  usize,
+    w:     f64,
+    skip:  usize,
+}
+
+impl<T> Reservoir<T> {
+    /// Create an empty reservoir which keeps at most `k` items.
+    #[inline]
+    pub fn new(k: usize) -> Self {
+        Reservoir {
+            k,
+            items: Vec::with_capacity(k),
+            seen: 0,
+            w: 1.0,
+            skip: 0,
+        }
+    }
+
+    /// The maximum number of items to keep.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.k
+    }
+
+    /// The number of items which have been fed.
+    #[inline]
+    pub fn seen(&self) -> usize {
+        self.seen
+    }
+
+    /// The kept items, in no particular order.
+    #[inline]
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Take the kept items, in no particular order.
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.items
+    }
+
+    /// Feed an item with a new lazily-initialized thread-local random number generator.
+    #[inline]
+    pub fn push(&mut self, item: T) {
+        self.push_with_rng(item, &mut default_rng())
+    }
+
+    /// Feed an item with an existing random number generator.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use random_number::Reservoir;
+    ///
+    /// let mut rng = random_number::rand::thread_rng();
+    ///
+    /// let mut reservoir = Reservoir::new(3);
+    ///
+    /// for line in "a\nb\nc\nd\ne\nf".lines() {
+    ///     reservoir.push_with_rng(line, &mut rng);
+    /// }
+    ///
+    /// assert_eq!(6, reservoir.seen());
+    /// assert_eq!(3, reservoir.items().len());
+    /// ```
+    pub fn push_with_rng<R: Rng>(&mut self, item: T, rng: &mut R) {
+        self.seen = self.seen.saturating_add(1);
+
+        if self.items.len() < self.k {
+            self.items.push(item);
+
+            if self.items.len() == self.k {
+                self.w = next_w_factor(self.k, rng);
+                self.skip = next_skip(self.w, rng);
+            }
+        } else if self.k > 0 {
+            if self.skip > 0 {
+                self.skip -= 1;
+            } else {
+                let i = rng.gen_range(0..self.k);
+
+                self.items[i] = item;
+
+                self.w *= next_w_factor(self.k, rng);
+                self.skip = next_skip(self.w, rng);
+            }
+        }
+    }
+
+    /// Feed items with an existing random number generator.
+    #[inline]
+    pub fn extend_with_rng<I: IntoIterator<Item = T>, R: Rng>(&mut self, iter: I, rng: &mut R) {
+        for item in iter {
+            self.push_with_rng(item, rng);
+        }
+    }
+}
+
+impl<T> Extend<T> for Reservoir<T> {
+    /// Feed items with a new lazily-initialized thread-local random number generator.
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.extend_with_rng(iter, &mut default_rng())
+    }
+}
@@ -0,0 +1,81 @@
+/// Types whose values can be shrunk toward a lower bound by bisection, for the `proptest` and `quickcheck` integrations.
+///
+/// ## Examples
+///
+/// ```rust
+/// use random_number::Shrinkable;
+///
+/// assert_eq!(0.0, <f64 as Shrinkable>::midpoint(-1e308, 1e308));
+/// assert!(<f64 as Shrinkable>::midpoint(f64::MAX / 2.0, f64::MAX) < f64::MAX);
+/// assert_eq!(1.0, <f64 as Shrinkable>::midpoint(1.0, 1.0f64.successor()));
+/// assert_eq!(-1, <i8 as Shrinkable>::midpoint(i8::MIN, i8::MAX));
+/// ```
+pub trait Shrinkable: Copy + PartialOrd {
+    /// The value halfway from `low` to `high`, rounded toward `low`. `low` must not be greater than `high`.
+    fn midpoint(low: Self, high: Self) -> Self;
+
+    /// The smallest value greater than `self`. `self` must not be the greatest value.
+    fn successor(self) -> Self;
+}
+
+macro_rules! shrinkable_integer_impl {
+    ($t:ident, $u:ident) => {
+        impl Shrinkable for $t {
+            #[inline]
+            fn midpoint(low: Self, high: Self) -> Self {
+                // the distance may not fit in a signed type
+                low.wrapping_add(((high as $u).wrapping_sub(low as $u) / 2) as $t)
+            }
+
+            #[inline]
+            fn successor(self) -> Self {
+                self + 1
+            }
+        }
+    };
+}
+
+shrinkable_integer_impl!(u8, u8);
+shrinkable_integer_impl!(u16, u16);
+shrinkable_integer_impl!(u32, u32);
+shrinkable_integer_impl!(u64, u64);
+shrinkable_integer_impl!(u128, u128);
+shrinkable_integer_impl!(usize, usize);
+shrinkable_integer_impl!(i8, u8);
+shrinkable_integer_impl!(i16, u16);
+shrinkable_integer_impl!(i32, u32);
+shrinkable_integer_impl!(i64, u64);
+shrinkable_integer_impl!(i128, u128);
+shrinkable_integer_impl!(isize, usize);
+
+macro_rules! shrinkable_float_impl {
+    ($t:ident) => {
+        impl Shrinkable for $t {
+            #[inline]
+            fn midpoint(low: Self, high: Self) -> Self {
+                // halving each bound first never overflows, but rounding may move the result out of the bounds
+                let midpoint = low / 2.0 + high / 2.0;
+
+                if midpoint < high && midpoint > low {
+                    midpoint
+                } else {
+                    low
+                }
+            }
+
+            #[inline]
+            fn successor(self) -> Self {
+                if self == 0.0 {
+                    $t::from_bits(1)
+                } else if self > 0.0 {
+                    $t::from_bits(self.to_bits() + 1)
+                } else {
+                    $t::from_bits(self.to_bits() - 1)
+                }
+            }
+        }
+    };
+}
+
+shrinkable_float_impl!(f32);
+shrinkable_float_impl!(f64);
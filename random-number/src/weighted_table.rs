@@ -0,0 +1,166 @@
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hash},
+    iter::FusedIterator,
+};
+
+use crate::{default_rng, rand::Rng, AliasTable, DefaultRng, WeightError};
+
+/// A table of items with weights for sampling in O(1) time, built by Vose's alias method.
+#[derive(Debug, Clone)]
+pub struct WeightedTable<T> {
+    items: Vec<T>,
+    table: AliasTable,
+}
+
+impl<T> WeightedTable<T> {
+    /// Build a table from `(item, weight)` pairs.
+    pub fn new<I: IntoIterator<Item = (T, f64)>>(pairs: I) -> Result<Self, WeightError> {
+        let (items, weights): (Vec<T>, Vec<f64>) = pairs.into_iter().unzip();
+
+        let table = AliasTable::new(&weights)?;
+
+        Ok(WeightedTable {
+            items,
+            table,
+        })
+    }
+
+    /// Build a table from a map whose values are the weights of its keys.
+    #[inline]
+    pub fn from_map<S: BuildHasher>(map: HashMap<T, f64, S>) -> Result<Self, WeightError>
+    where
+        T: Eq + Hash, {
+        Self::new(map)
+    }
+
+    /// The number of items.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Always `false`, because a table has at least one item.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// The items in the order they were given.
+    #[inline]
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Sample an item with a new lazily-initialized thread-local random number generator.
+    #[inline]
+    pub fn sample(&self) -> &T {
+        self.sample_with_rng(&mut default_rng())
+    }
+
+    /// Sample an item with an existing random number generator.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use random_number::WeightedTable;
+    ///
+    /// let mut rng = random_number::rand::thread_rng();
+    ///
+    /// let table = WeightedTable::new(vec![
+    ///     ("common", 9.0),
+    ///     ("rare", 1.0),
+    ///     ("never", 0.0),
+    /// ])
+    /// .unwrap();
+    ///
+    /// for _ in 0..100 {
+    ///     assert_ne!("never", *table.sample_with_rng(&mut rng));
+    /// }
+    /// ```
+    #[inline]
+    pub fn sample_with_rng<R: Rng>(&self, rng: &mut R) -> &T {
+        &self.items[self.table.sample_with_rng(rng)]
+    }
+
+    /// Fill a slice with sampled items with a new lazily-initialized thread-local random number generator.
+    #[inline]
+    pub fn fill(&self, out: &mut [T])
+    where
+        T: Clone, {
+        self.fill_with_rng(out, &mut default_rng())
+    }
+
+    /// Fill a slice with sampled items with an existing random number generator.
+    #[inline]
+    pub fn fill_with_rng<R: Rng>(&self, out: &mut [T], rng: &mut R)
+    where
+        T: Clone, {
+        for x in out.iter_mut() {
+            x.clone_from(self.sample_with_rng(rng));
+        }
+    }
+
+    /// Create an endless iterator of sampled items with a new lazily-initialized thread-local random number generator.
+    #[inline]
+    pub fn iter(&self) -> WeightedTableIter<'_, T, DefaultRng> {
+        WeightedTableIter {
+            table: self, rng: default_rng()
+        }
+    }
+
+    /// Create an endless iterator of sampled items with an existing random number generator.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use random_number::WeightedTable;
+    ///
+    /// let mut rng = random_number::rand::thread_rng();
+    ///
+    /// let mut map = HashMap::new();
+    /// map.insert('a', 1.0);
+    /// map.insert('b', 3.0);
+    ///
+    /// let table = WeightedTable::from_map(map).unwrap();
+    ///
+    /// let s: String = table.iter_with_rng(&mut rng).take(10).collect();
+    ///
+    /// assert_eq!(10, s.len());
+    /// ```
+    #[inline]
+    pub fn iter_with_rng<'a, R: Rng>(
+        &'a self,
+        rng: &'a mut R,
+    ) -> WeightedTableIter<'a, T, &'a mut R> {
+        WeightedTableIter {
+            table: self,
+            rng,
+        }
+    }
+}
+
+/// An endless iterator of items sampled from a `WeightedTable`.
+#[derive(Debug)]
+pub struct WeightedTableIter<'a, T, R: Rng> {
+    table: &'a WeightedTable<T>,
+    rng:   R,
+}
+
+impl<'a, T, R: Rng> Iterator for WeightedTableIter<'a, T, R> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.table.sample_with_rng(&mut self.rng))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<'a, T, R: Rng> FusedIterator for WeightedTableIter<'a, T, R> {}
@@ -0,0 +1,203 @@
+use random_number::{
+    diagnostics::{chi_square_test, kolmogorov_smirnov_test, runs_test},
+    generators::Xoshiro256StarStar,
+    rand::SeedableRng,
+};
+
+const SAMPLES: usize = 100_000;
+const BUCKETS: usize = 256;
+const ALPHA: f64 = 1e-4;
+
+/// Check that offsets from the start of a range, which are in [0, `max_offset`], are uniform and independent.
+fn check_offsets(name: &str, offsets: &[u128], max_offset: u128) {
+    let n = offsets.len() as f64;
+
+    let (observed, expected) = if max_offset < 1024 {
+        // one category for each value
+        let k = max_offset as usize + 1;
+
+        let mut observed = vec![0u64; k];
+
+        for &o in offsets {
+            observed[o as usize] += 1;
+        }
+
+        (observed, vec![n / k as f64; k])
+    } else if max_offset < 1 << 64 {
+        // equal-width buckets whose exact sizes differ by at most one value
+        let span = max_offset + 1;
+        let buckets = BUCKETS as u128;
+
+        let mut observed = vec![0u64; BUCKETS];
+
+        for &o in offsets {
+            observed[(o * buckets / span) as usize] += 1;
+        }
+
+        let start = |b: u128| (b * span + buckets - 1) / buckets;
+
+        let expected =
+            (0..buckets).map(|b| (start(b + 1) - start(b)) as f64 / span as f64 * n).collect();
+
+        (observed, expected)
+    } else {
+        let span = max_offset as f64 + 1.0;
+
+        let mut observed = vec![0u64; BUCKETS];
+
+        for &o in offsets {
+            observed[((o as f64 / span * BUCKETS as f64) as usize).min(BUCKETS - 1)] += 1;
+        }
+
+        (observed, vec![n / BUCKETS as f64; BUCKETS])
+    };
+
+    let result = chi_square_test(&observed, &expected);
+
+    assert!(result.passes(ALPHA), "{}: chi-square {:?}", name, result);
+
+    if max_offset >= 1 << 16 {
+        let span = max_offset as f64 + 1.0;
+
+        let unit: Vec<f64> = offsets.iter().map(|&o| o as f64 / span).collect();
+
+        let result = kolmogorov_smirnov_test(&unit, |x| x);
+
+        assert!(result.passes(ALPHA), "{}: Kolmogorov-Smirnov {:?}", name, result);
+    }
+
+    let values: Vec<f64> = offsets.iter().map(|&o| o as f64).collect();
+
+    let result = runs_test(&values);
+
+    assert!(result.passes(ALPHA), "{}: runs {:?}", name, result);
+}
+
+/// Check that values in [`min`, `max`) are uniform and independent.
+fn check_floats(name: &str, values: &[f64], min: f64, max: f64) {
+    let unit: Vec<f64> = values.iter().map(|&x| (x - min) / (max - min)).collect();
+
+    let mut observed = vec![0u64; BUCKETS];
+
+    for &u in unit.iter() {
+        assert!((0.0..=1.0).contains(&u), "{}: {} is out of range", name, u);
+
+        observed[((u * BUCKETS as f64) as usize).min(BUCKETS - 1)] += 1;
+    }
+
+    let result = chi_square_test(&observed, &vec![unit.len() as f64 / BUCKETS as f64; BUCKETS]);
+
+    assert!(result.passes(ALPHA), "{}: chi-square {:?}", name, result);
+
+    let result = kolmogorov_smirnov_test(&unit, |x| x);
+
+    assert!(result.passes(ALPHA), "{}: Kolmogorov-Smirnov {:?}", name, result);
+
+    let result = runs_test(&unit);
+
+    assert!(result.passes(ALPHA), "{}: runs {:?}", name, result);
+}
+
+macro_rules! check_integers {
+    ($t:ty, $range:expr, $min:expr, $max:expr, $seed:expr) => {{
+        let name = concat!(stringify!($t), " ", stringify!($range));
+
+        let mut rng = Xoshiro256StarStar::seed_from_u64($seed);
+
+        let offsets: Vec<u128> = (0..SAMPLES)
+            .map(|_| {
+                let x: $t = random_number::random_ranged_with_rng($range, &mut rng);
+
+                (x as u128).wrapping_sub($min as u128)
+            })
+            .collect();
+
+        check_offsets(
+            &format!("random_ranged {}", name),
+            &offsets,
+            ($max as u128).wrapping_sub($min as u128),
+        );
+
+        let mut values: Vec<$t> = vec![0; SAMPLES];
+
+        random_number::random_fill_ranged_with_rng(&mut values, $range, &mut rng);
+
+        let offsets: Vec<u128> =
+            values.iter().map(|&x| (x as u128).wrapping_sub($min as u128)).collect();
+
+        check_offsets(
+            &format!("random_fill_ranged {}", name),
+            &offsets,
+            ($max as u128).wrapping_sub($min as u128),
+        );
+    }};
+}
+
+macro_rules! check_floats {
+    ($t:ty, $range:expr, $min:expr, $max:expr, $seed:expr) => {{
+        let name = concat!(stringify!($t), " ", stringify!($range));
+
+        let mut rng = Xoshiro256StarStar::seed_from_u64($seed);
+
+        let values: Vec<f64> = (0..SAMPLES)
+            .map(|_| {
+                let x: $t = random_number::random_ranged_with_rng($range, &mut rng);
+
+                x as f64
+            })
+            .collect();
+
+        check_floats(&format!("random_ranged {}", name), &values, $min as f64, $max as f64);
+
+        let mut values: Vec<$t> = vec![0.0; SAMPLES];
+
+        random_number::random_fill_ranged_with_rng(&mut values, $range, &mut rng);
+
+        let values: Vec<f64> = values.iter().map(|&x| x as f64).collect();
+
+        check_floats(&format!("random_fill_ranged {}", name), &values, $min as f64, $max as f64);
+    }};
+}
+
+#[test]
+fn unsigned_integers() {
+    check_integers!(u8, 0..=200, 0u8, 200u8, 1);
+    check_integers!(u8, 3..7, 3u8, 6u8, 2);
+    check_integers!(u8, .., u8::MIN, u8::MAX, 3);
+    check_integers!(u16, 1..=60000, 1u16, 60000u16, 4);
+    check_integers!(u16, .., u16::MIN, u16::MAX, 5);
+    check_integers!(u32, 0..=3_000_000_000, 0u32, 3_000_000_000u32, 6);
+    check_integers!(u32, .., u32::MIN, u32::MAX, 7);
+    check_integers!(u64, 10..=u64::MAX / 3 * 2, 10u64, u64::MAX / 3 * 2, 8);
+    check_integers!(u64, .., u64::MIN, u64::MAX, 9);
+    check_integers!(u128, 0..=u128::MAX / 3 * 2, 0u128, u128::MAX / 3 * 2, 10);
+    check_integers!(u128, .., u128::MIN, u128::MAX, 11);
+    check_integers!(usize, 0..1000, 0usize, 999usize, 12);
+    check_integers!(usize, .., usize::MIN, usize::MAX, 13);
+}
+
+#[test]
+fn signed_integers() {
+    check_integers!(i8, -100..=27, -100i8, 27i8, 21);
+    check_integers!(i8, .., i8::MIN, i8::MAX, 22);
+    check_integers!(i16, -30000..30001, -30000i16, 30000i16, 23);
+    check_integers!(i16, .., i16::MIN, i16::MAX, 24);
+    check_integers!(i32, i32::MIN..=1_000_000_000, i32::MIN, 1_000_000_000i32, 25);
+    check_integers!(i32, .., i32::MIN, i32::MAX, 26);
+    check_integers!(i64, -5..=i64::MAX, -5i64, i64::MAX, 27);
+    check_integers!(i64, .., i64::MIN, i64::MAX, 28);
+    check_integers!(i128, i128::MIN / 3..=i128::MAX / 3 * 2, i128::MIN / 3, i128::MAX / 3 * 2, 29);
+    check_integers!(i128, .., i128::MIN, i128::MAX, 30);
+    check_integers!(isize, -500..500, -500isize, 499isize, 31);
+    check_integers!(isize, .., isize::MIN, isize::MAX, 32);
+}
+
+#[test]
+fn floats() {
+    check_floats!(f64, 0.0..1.0, 0.0, 1.0, 41);
+    check_floats!(f64, -3.5..=7.25, -3.5, 7.25, 42);
+    check_floats!(f64, .., 0.0, 1.0, 43);
+    check_floats!(f32, 0.0..1.0, 0.0, 1.0, 44);
+    check_floats!(f32, -1e6..=1e6, -1e6, 1e6, 45);
+    check_floats!(f32, .., 0.0, 1.0, 46);
+}